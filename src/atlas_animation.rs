@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use bevy::{
+    ecs::{
+        component::Component,
+        hierarchy::ChildOf,
+        relationship::RelatedSpawnerCommands,
+        system::{EntityCommands, Query, Res},
+    },
+    time::Time,
+    ui::{Node, widget::ImageNode},
+};
+
+use crate::{Element, UiContext};
+
+#[derive(Component, Clone)]
+pub struct AtlasAnimation {
+    pub first: usize,
+    pub last: usize,
+    pub fps: f32,
+    pub looping: bool,
+    pub playing: bool,
+    elapsed: f32,
+}
+
+impl AtlasAnimation {
+    #[inline]
+    pub fn new(first: usize, last: usize, fps: f32) -> Self {
+        Self {
+            first,
+            last,
+            fps,
+            looping: true,
+            playing: true,
+            elapsed: 0.0,
+        }
+    }
+
+    #[inline]
+    pub fn restart(&mut self) {
+        self.elapsed = 0.0;
+        self.playing = true;
+    }
+}
+
+pub struct AtlasAnimated<E: Element> {
+    pub content: E,
+    pub animation: AtlasAnimation,
+}
+
+impl<E: Element> AtlasAnimated<E> {
+    #[inline]
+    pub fn new(content: E, first: usize, last: usize, fps: f32) -> Self {
+        Self {
+            content,
+            animation: AtlasAnimation::new(first, last, fps),
+        }
+    }
+
+    #[inline]
+    pub fn once(mut self) -> Self {
+        self.animation.looping = false;
+        self
+    }
+
+    #[inline]
+    pub fn paused(mut self) -> Self {
+        self.animation.playing = false;
+        self
+    }
+}
+
+impl<E: Element> Element for AtlasAnimated<E> {
+    type Bundle = (AtlasAnimation, E::Bundle);
+
+    #[inline]
+    fn modify_node(&self, node: &mut Node, context: &UiContext) {
+        self.content.modify_node(node, context);
+    }
+
+    #[inline]
+    fn create_bundle(&self, context: &UiContext) -> Self::Bundle {
+        (self.animation.clone(), self.content.create_bundle(context))
+    }
+
+    #[inline]
+    fn register_observers(&self, entity_command: &mut EntityCommands, context: &UiContext) {
+        self.content.register_observers(entity_command, context);
+    }
+
+    #[inline]
+    fn spawn_children(&self, rcs: &mut RelatedSpawnerCommands<ChildOf>, context: Arc<UiContext>) {
+        self.content.spawn_children(rcs, context);
+    }
+}
+
+pub(crate) fn animate_atlas(time: Res<Time>, query: Query<(&mut AtlasAnimation, &mut ImageNode)>) {
+    for (mut animation, mut image) in query {
+        if !animation.playing || animation.fps <= 0.0 || animation.last < animation.first {
+            continue;
+        }
+        let Some(current) = image.texture_atlas.as_ref().map(|a| a.index) else {
+            continue;
+        };
+        animation.elapsed += time.delta_secs();
+        let frames = animation.last - animation.first + 1;
+        let mut frame = (animation.elapsed * animation.fps) as usize;
+        if frame >= frames {
+            if animation.looping {
+                frame %= frames;
+            } else {
+                frame = frames - 1;
+                animation.playing = false;
+            }
+        }
+        let index = animation.first + frame;
+        if current != index
+            && let Some(atlas) = image.texture_atlas.as_mut()
+        {
+            atlas.index = index;
+        }
+    }
+}
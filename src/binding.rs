@@ -0,0 +1,237 @@
+use std::sync::Arc;
+
+use bevy::{
+    ecs::{
+        component::{Component, Mutable},
+        entity::Entity,
+        hierarchy::ChildOf,
+        observer::On,
+        relationship::RelatedSpawnerCommands,
+        resource::Resource,
+        system::{Commands, EntityCommands, Query},
+        world::{EntityRef, EntityWorldMut, World},
+    },
+    ui::{Checked, Node},
+    ui_widgets::{SliderValue, ValueChange},
+};
+
+use crate::{Element, UiContext, dropdown::DropdownState, text_input::TextInputValue};
+
+type Reader<T> = Arc<dyn Fn(&World) -> Option<T> + Send + Sync>;
+type Writer<T> = Arc<dyn Fn(&mut World, T) + Send + Sync>;
+
+pub struct Binding<T> {
+    read: Reader<T>,
+    write: Writer<T>,
+}
+
+impl<T> Clone for Binding<T> {
+    fn clone(&self) -> Self {
+        Self {
+            read: self.read.clone(),
+            write: self.write.clone(),
+        }
+    }
+}
+
+impl<T: 'static> Binding<T> {
+    #[inline]
+    pub fn new<RF, WF>(read: RF, write: WF) -> Self
+    where
+        RF: Fn(&World) -> Option<T> + Send + Sync + 'static,
+        WF: Fn(&mut World, T) + Send + Sync + 'static,
+    {
+        Self {
+            read: Arc::new(read),
+            write: Arc::new(write),
+        }
+    }
+
+    #[inline]
+    pub fn resource<R: Resource, G, S>(get: G, set: S) -> Self
+    where
+        G: Fn(&R) -> T + Send + Sync + 'static,
+        S: Fn(&mut R, T) + Send + Sync + 'static,
+    {
+        Self::new(
+            move |world| world.get_resource::<R>().map(&get),
+            move |world, value| {
+                if let Some(mut r) = world.get_resource_mut::<R>() {
+                    set(&mut r, value);
+                }
+            },
+        )
+    }
+
+    #[inline]
+    pub fn component<C: Component<Mutability = Mutable>, G, S>(
+        entity: Entity,
+        get: G,
+        set: S,
+    ) -> Self
+    where
+        G: Fn(&C) -> T + Send + Sync + 'static,
+        S: Fn(&mut C, T) + Send + Sync + 'static,
+    {
+        Self::new(
+            move |world| world.get::<C>(entity).map(&get),
+            move |world, value| {
+                if let Some(mut c) = world.get_mut::<C>(entity) {
+                    set(&mut c, value);
+                }
+            },
+        )
+    }
+
+    #[inline]
+    pub fn read(&self, world: &World) -> Option<T> {
+        (self.read)(world)
+    }
+
+    #[inline]
+    pub fn write(&self, world: &mut World, value: T) {
+        (self.write)(world, value)
+    }
+}
+
+pub trait BindingValue: PartialEq + Clone + Send + Sync + 'static {
+    fn widget_value(entity: EntityRef) -> Option<Self>;
+    fn set_widget_value(entity: EntityWorldMut, value: Self);
+}
+
+impl BindingValue for f32 {
+    #[inline]
+    fn widget_value(entity: EntityRef) -> Option<Self> {
+        entity.get::<SliderValue>().map(|v| v.0)
+    }
+
+    #[inline]
+    fn set_widget_value(mut entity: EntityWorldMut, value: Self) {
+        entity.insert(SliderValue(value));
+    }
+}
+
+impl BindingValue for bool {
+    #[inline]
+    fn widget_value(entity: EntityRef) -> Option<Self> {
+        Some(entity.contains::<Checked>())
+    }
+
+    #[inline]
+    fn set_widget_value(mut entity: EntityWorldMut, value: Self) {
+        if value {
+            entity.insert(Checked);
+        } else {
+            entity.remove::<Checked>();
+        }
+    }
+}
+
+impl BindingValue for String {
+    #[inline]
+    fn widget_value(entity: EntityRef) -> Option<Self> {
+        entity.get::<TextInputValue>().map(|v| v.0.clone())
+    }
+
+    #[inline]
+    fn set_widget_value(mut entity: EntityWorldMut, value: Self) {
+        entity.insert(TextInputValue(value));
+    }
+}
+
+impl BindingValue for usize {
+    #[inline]
+    fn widget_value(entity: EntityRef) -> Option<Self> {
+        entity.get::<DropdownState>().map(|d| d.selected)
+    }
+
+    #[inline]
+    fn set_widget_value(mut entity: EntityWorldMut, value: Self) {
+        if let Some(mut dropdown) = entity.get_mut::<DropdownState>() {
+            dropdown.selected = value;
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct BoundValue<T: BindingValue> {
+    pub binding: Binding<T>,
+}
+
+pub struct Bound<E: Element, T: BindingValue> {
+    pub binding: Binding<T>,
+    pub content: E,
+}
+
+impl<E: Element, T: BindingValue> Bound<E, T> {
+    #[inline]
+    pub fn new(binding: Binding<T>, content: E) -> Self {
+        Self { binding, content }
+    }
+}
+
+impl<E: Element, T: BindingValue> Element for Bound<E, T> {
+    type Bundle = (BoundValue<T>, E::Bundle);
+
+    #[inline]
+    fn modify_node(&self, node: &mut Node, context: &UiContext) {
+        self.content.modify_node(node, context);
+    }
+
+    #[inline]
+    fn create_bundle(&self, context: &UiContext) -> Self::Bundle {
+        (
+            BoundValue {
+                binding: self.binding.clone(),
+            },
+            self.content.create_bundle(context),
+        )
+    }
+
+    #[inline]
+    fn register_observers(&self, entity_command: &mut EntityCommands, context: &UiContext) {
+        self.content.register_observers(entity_command, context);
+        entity_command.observe(write_bound_value::<T>);
+    }
+
+    #[inline]
+    fn spawn_children(&self, rcs: &mut RelatedSpawnerCommands<ChildOf>, context: Arc<UiContext>) {
+        self.content.spawn_children(rcs, context);
+    }
+}
+
+pub fn ignore_value_change<T: Send + Sync + 'static>(_on: On<ValueChange<T>>) {}
+
+fn write_bound_value<T: BindingValue>(
+    on: On<ValueChange<T>>,
+    query: Query<&BoundValue<T>>,
+    mut commands: Commands,
+) {
+    let Ok(bound) = query.get(on.source) else {
+        return;
+    };
+    let binding = bound.binding.clone();
+    let value = on.value.clone();
+    commands.queue(move |world: &mut World| {
+        if binding.read(world).as_ref() != Some(&value) {
+            binding.write(world, value);
+        }
+    });
+}
+
+pub(crate) fn sync_bound_values<T: BindingValue>(world: &mut World) {
+    let mut query = world.query::<(Entity, &BoundValue<T>)>();
+    let changed: Vec<(Entity, T)> = query
+        .iter(world)
+        .filter_map(|(e, bound)| {
+            let source = bound.binding.read(world)?;
+            let current = T::widget_value(world.entity(e));
+            (current.as_ref() != Some(&source)).then_some((e, source))
+        })
+        .collect();
+    for (e, value) in changed {
+        if let Ok(entity) = world.get_entity_mut(e) {
+            T::set_widget_value(entity, value);
+        }
+    }
+}
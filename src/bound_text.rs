@@ -0,0 +1,219 @@
+use std::{fmt::Display, sync::Arc};
+
+use bevy::{
+    ecs::{
+        component::Component, entity::Entity, hierarchy::ChildOf,
+        relationship::RelatedSpawnerCommands, resource::Resource, system::EntityCommands,
+        world::World,
+    },
+    ui::{Node, widget::Text as UiText},
+};
+
+use crate::{Element, Text, TextSizing, UiContext, binding::Binding};
+
+type SourceFn = Arc<dyn Fn(&World) -> Option<String> + Send + Sync>;
+
+#[derive(Clone)]
+pub struct TextSource(SourceFn);
+
+impl TextSource {
+    #[inline]
+    pub fn resource<R: Resource, T: Display, F>(get: F) -> Self
+    where
+        F: Fn(&R) -> T + Send + Sync + 'static,
+    {
+        Self(Arc::new(move |world| {
+            world.get_resource::<R>().map(|r| get(r).to_string())
+        }))
+    }
+
+    #[inline]
+    pub fn component<C: Component, T: Display, F>(entity: Entity, get: F) -> Self
+    where
+        F: Fn(&C) -> T + Send + Sync + 'static,
+    {
+        Self(Arc::new(move |world| {
+            world.get::<C>(entity).map(|c| get(c).to_string())
+        }))
+    }
+
+    #[inline]
+    pub fn world<T: Display, F>(get: F) -> Self
+    where
+        F: Fn(&World) -> Option<T> + Send + Sync + 'static,
+    {
+        Self(Arc::new(move |world| get(world).map(|v| v.to_string())))
+    }
+}
+
+impl<T: Display + 'static> From<Binding<T>> for TextSource {
+    #[inline]
+    fn from(binding: Binding<T>) -> Self {
+        Self(Arc::new(move |world| {
+            binding.read(world).map(|v| v.to_string())
+        }))
+    }
+}
+
+pub struct BoundText {
+    pub template: String,
+    pub sizing: TextSizing,
+    pub sources: Vec<TextSource>,
+}
+
+impl BoundText {
+    #[inline]
+    pub fn new<T: Into<String>>(template: T, sizing: TextSizing) -> Self {
+        Self {
+            template: template.into(),
+            sizing,
+            sources: vec![],
+        }
+    }
+
+    #[inline]
+    pub fn with_source<S: Into<TextSource>>(mut self, source: S) -> Self {
+        self.sources.push(source.into());
+        self
+    }
+}
+
+#[derive(Component)]
+pub struct BoundTextTemplate {
+    pub template: String,
+    pub sources: Vec<TextSource>,
+}
+
+impl BoundTextTemplate {
+    pub fn format(&self, world: &World) -> String {
+        let values: Vec<String> = self
+            .sources
+            .iter()
+            .map(|s| (s.0)(world).unwrap_or_default())
+            .collect();
+        format_template(&self.template, &values)
+    }
+}
+
+pub fn format_template(template: &str, values: &[String]) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut next = 0;
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                result.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                result.push('}');
+            }
+            '{' => {
+                let mut placeholder = String::new();
+                let mut closed = false;
+                for c in chars.by_ref() {
+                    if c == '}' {
+                        closed = true;
+                        break;
+                    }
+                    placeholder.push(c);
+                }
+                if !closed {
+                    result.push('{');
+                    result.push_str(&placeholder);
+                    break;
+                }
+                let index = if placeholder.is_empty() {
+                    next += 1;
+                    Some(next - 1)
+                } else {
+                    placeholder.trim().parse().ok()
+                };
+                match index.and_then(|i| values.get(i)) {
+                    Some(value) => result.push_str(value),
+                    None => {
+                        result.push('{');
+                        result.push_str(&placeholder);
+                        result.push('}');
+                    }
+                }
+            }
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+impl Element for BoundText {
+    type Bundle = (BoundTextTemplate, <Text as Element>::Bundle);
+
+    #[inline]
+    fn create_bundle(&self, context: &UiContext) -> Self::Bundle {
+        (
+            BoundTextTemplate {
+                template: self.template.clone(),
+                sources: self.sources.clone(),
+            },
+            Text::new(String::new(), self.sizing).create_bundle(context),
+        )
+    }
+
+    #[inline]
+    fn register_observers(&self, _entity_command: &mut EntityCommands, _context: &UiContext) {}
+
+    #[inline]
+    fn spawn_children(&self, _rcs: &mut RelatedSpawnerCommands<ChildOf>, _context: Arc<UiContext>) {
+    }
+
+    #[inline]
+    fn modify_node(&self, _node: &mut Node, _context: &UiContext) {}
+}
+
+pub(crate) fn update_bound_text(world: &mut World) {
+    let mut query = world.query::<(Entity, &BoundTextTemplate, &UiText)>();
+    let changed: Vec<(Entity, String)> = query
+        .iter(world)
+        .filter_map(|(e, template, text)| {
+            let formatted = template.format(world);
+            (formatted != text.0).then_some((e, formatted))
+        })
+        .collect();
+    for (e, formatted) in changed {
+        if let Some(mut text) = world.get_mut::<UiText>(e) {
+            text.0 = formatted;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_template;
+
+    fn values(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn formats_positional_and_indexed_placeholders() {
+        let values = values(&["10", "gold"]);
+        assert_eq!(format_template("{} {}", &values), "10 gold");
+        assert_eq!(format_template("{1}: {0}", &values), "gold: 10");
+        assert_eq!(format_template("{{{}}}", &values), "{10}");
+    }
+
+    #[test]
+    fn keeps_unknown_placeholders() {
+        let values = values(&["10"]);
+        assert_eq!(format_template("{gold} {}", &values), "{gold} 10");
+        assert_eq!(format_template("{} {}", &values), "10 {}");
+        assert_eq!(format_template("{3}", &values), "{3}");
+    }
+
+    #[test]
+    fn keeps_unclosed_placeholder() {
+        let values = values(&["10"]);
+        assert_eq!(format_template("a {0", &values), "a {0");
+        assert_eq!(format_template("{} {", &values), "10 {");
+    }
+}
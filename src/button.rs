@@ -11,7 +11,8 @@ use bevy::{
 };
 
 use crate::{
-    Element, IntoChild, Text, TextSizing, UiContext, centered::Centered, child::Child, sized::Sized,
+    Element, IntoChild, Text, TextSizing, UiContext, centered::Centered, child::Child,
+    localization::LocalizedText, sized::Sized,
 };
 use wane_observers::{EntityObserverRegistration, IntoEntityObserverRegistration};
 
@@ -61,6 +62,24 @@ pub fn button_with_text<F, M: 'static>(
     height: Val,
     on_click: F,
 ) -> impl Element
+where
+    F: IntoObserverSystem<Activate, (), M> + Copy + Send + Sync,
+{
+    Sized {
+        width,
+        height,
+        content: Centered {
+            content: Button::new(on_click, Text::new(text, TextSizing::Big).into_child()),
+        },
+    }
+}
+
+pub fn button_with_key<F, M: 'static, K: Into<String>>(
+    key: K,
+    width: Val,
+    height: Val,
+    on_click: F,
+) -> impl Element
 where
     F: IntoObserverSystem<Activate, (), M> + Copy + Send + Sync,
 {
@@ -70,11 +89,7 @@ where
         content: Centered {
             content: Button::new(
                 on_click,
-                Text {
-                    text,
-                    sizing: TextSizing::Big,
-                }
-                .into_child(),
+                LocalizedText::new(key, TextSizing::Big).into_child(),
             ),
         },
     }
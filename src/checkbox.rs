@@ -13,7 +13,11 @@ use bevy::{
 use wane_observers::{EntityObserverRegistration, IntoEntityObserverRegistration};
 
 use crate::{
-    Element, IntoChild, UiContext, background::Background, border::Border, centered::Centered,
+    Element, IntoChild, UiContext,
+    background::Background,
+    binding::{Binding, Bound, ignore_value_change},
+    border::Border,
+    centered::Centered,
     sized::Sized,
 };
 
@@ -49,6 +53,11 @@ impl Checkbox<()> {
             .into_child(),
         }
     }
+
+    #[inline]
+    pub fn new_bound(binding: Binding<bool>) -> impl Element {
+        Bound::new(binding, Checkbox::new_default(&ignore_value_change::<bool>))
+    }
 }
 
 impl<E: Element> Checkbox<E> {
@@ -26,4 +26,9 @@ impl Element for Child {
     fn spawn_children(&self, rcs: &mut RelatedSpawnerCommands<ChildOf>, context: Arc<UiContext>) {
         self.content.spawn(rcs, context);
     }
+
+    #[inline]
+    fn spawn_as_child(&self, rcs: &mut RelatedSpawnerCommands<ChildOf>, context: Arc<UiContext>) {
+        self.content.spawn(rcs, context);
+    }
 }
@@ -1,27 +1,105 @@
+use std::sync::{Arc, Mutex};
+
 use bevy::{
-    asset::Handle,
+    asset::{Assets, Handle},
+    ecs::{
+        component::Component,
+        entity::Entity,
+        observer::On,
+        system::{Query, ResMut, SystemParam},
+        world::World,
+    },
+    log::warn,
     ui_render::prelude::{MaterialNode, UiMaterial},
 };
 
-use crate::{Element, IntoChild, child::Child};
+use crate::{Element, IntoChild, UiContext, binding::Binding, child::Child, events::Init};
+
+type MaterialFn<M> = Arc<dyn Fn(&UiContext) -> M + Send + Sync>;
+type MaterialUpdate<M> = Box<dyn FnOnce(&mut M)>;
+type MaterialBindingFn<M> = Box<dyn Fn(&World) -> Option<MaterialUpdate<M>> + Send + Sync>;
+type MaterialBindingFactory<M> = Arc<dyn Fn() -> MaterialBindingFn<M> + Send + Sync>;
+type MaterialSyncFn = Arc<dyn Fn(&mut World, Entity) + Send + Sync>;
+
+pub enum MaterialSource<M: UiMaterial> {
+    Handle(Handle<M>),
+    Value(M),
+    Themed(MaterialFn<M>),
+}
+
+#[derive(Component, Clone)]
+pub struct MaterialBindings(Vec<MaterialSyncFn>);
 
 pub struct CustomNode<M: UiMaterial> {
-    material: Handle<M>,
+    material: MaterialSource<M>,
+    bindings: Vec<MaterialBindingFactory<M>>,
     content: Child,
 }
 
 impl<M: UiMaterial> CustomNode<M> {
     #[inline]
     pub fn new<E: IntoChild>(content: E) -> Self {
+        Self::from_handle(Handle::default(), content)
+    }
+
+    #[inline]
+    pub fn from_handle<E: IntoChild>(material: Handle<M>, content: E) -> Self {
+        CustomNode {
+            material: MaterialSource::Handle(material),
+            bindings: vec![],
+            content: content.into_child(),
+        }
+    }
+
+    #[inline]
+    pub fn from_material<E: IntoChild>(material: M, content: E) -> Self {
+        CustomNode {
+            material: MaterialSource::Value(material),
+            bindings: vec![],
+            content: content.into_child(),
+        }
+    }
+
+    #[inline]
+    pub fn from_theme<E: IntoChild, F>(material: F, content: E) -> Self
+    where
+        F: Fn(&UiContext) -> M + Send + Sync + 'static,
+    {
         CustomNode {
-            material: Handle::default(),
+            material: MaterialSource::Themed(Arc::new(material)),
+            bindings: vec![],
             content: content.into_child(),
         }
     }
+
+    #[inline]
+    pub fn with_binding<T, F>(mut self, binding: Binding<T>, apply: F) -> Self
+    where
+        T: PartialEq + Clone + Send + Sync + 'static,
+        F: Fn(&mut M, T) + Send + Sync + 'static,
+    {
+        let apply = Arc::new(apply);
+        self.bindings.push(Arc::new(move || {
+            let binding = binding.clone();
+            let apply = apply.clone();
+            let last: Mutex<Option<T>> = Mutex::new(None);
+            Box::new(move |world| {
+                let value = binding.read(world)?;
+                let mut last = last.lock().ok()?;
+                if last.as_ref() == Some(&value) {
+                    return None;
+                }
+                *last = Some(value.clone());
+                let apply = apply.clone();
+                Some(Box::new(move |material: &mut M| apply(material, value)))
+            })
+        }));
+        self
+    }
 }
 
 impl<M: UiMaterial> Element for CustomNode<M> {
-    type Bundle = MaterialNode<M>;
+    type Bundle = (MaterialNode<M>, MaterialBindings);
 
     #[inline]
     fn modify_node(&self, node: &mut bevy::ui::Node, context: &super::UiContext) {
@@ -30,7 +108,14 @@ impl<M: UiMaterial> Element for CustomNode<M> {
 
     #[inline]
     fn create_bundle(&self, _context: &super::UiContext) -> Self::Bundle {
-        MaterialNode(self.material.clone())
+        let handle = match &self.material {
+            MaterialSource::Handle(handle) => handle.clone(),
+            MaterialSource::Value(_) | MaterialSource::Themed(_) => Handle::default(),
+        };
+        (
+            MaterialNode(handle),
+            MaterialBindings(self.bindings.iter().map(|b| material_sync(b())).collect()),
+        )
     }
 
     #[inline]
@@ -40,6 +125,29 @@ impl<M: UiMaterial> Element for CustomNode<M> {
         context: &super::UiContext,
     ) {
         self.content.register_observers(entity_command, context);
+        let material = match &self.material {
+            MaterialSource::Handle(_) if self.bindings.is_empty() => return,
+            MaterialSource::Handle(_) => None,
+            MaterialSource::Value(material) => Some(material.clone()),
+            MaterialSource::Themed(material) => Some(material(context)),
+        };
+        entity_command.observe(
+            move |on: On<Init>,
+                  mut materials: ResMut<Assets<M>>,
+                  mut nodes: Query<&mut MaterialNode<M>>| {
+                let Ok(mut node) = nodes.get_mut(on.entity) else {
+                    return;
+                };
+                let material = material.clone().or_else(|| materials.get(&node.0).cloned());
+                match material {
+                    Some(material) => node.0 = materials.add(material),
+                    None => warn!(
+                        "material for {:?} is not loaded, bindings will modify the shared asset",
+                        on.entity
+                    ),
+                }
+            },
+        );
     }
 
     #[inline]
@@ -51,3 +159,51 @@ impl<M: UiMaterial> Element for CustomNode<M> {
         self.content.spawn_children(rcs, context);
     }
 }
+
+#[derive(SystemParam)]
+pub struct CustomMaterials<'w, 's, M: UiMaterial> {
+    nodes: Query<'w, 's, &'static MaterialNode<M>>,
+    materials: ResMut<'w, Assets<M>>,
+}
+
+impl<M: UiMaterial> CustomMaterials<'_, '_, M> {
+    #[inline]
+    pub fn get(&self, entity: Entity) -> Option<&M> {
+        self.materials.get(&self.nodes.get(entity).ok()?.0)
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, entity: Entity) -> Option<&mut M> {
+        let handle = self.nodes.get(entity).ok()?.0.id();
+        self.materials.get_mut(handle)
+    }
+}
+
+fn material_sync<M: UiMaterial>(binding: MaterialBindingFn<M>) -> MaterialSyncFn {
+    Arc::new(move |world, entity| {
+        let Some(update) = binding(world) else {
+            return;
+        };
+        let Some(handle) = world.get::<MaterialNode<M>>(entity).map(|n| n.0.id()) else {
+            return;
+        };
+        if let Some(mut materials) = world.get_resource_mut::<Assets<M>>()
+            && let Some(material) = materials.get_mut(handle)
+        {
+            update(material);
+        }
+    })
+}
+
+pub(crate) fn sync_material_bindings(world: &mut World) {
+    let mut query = world.query::<(Entity, &MaterialBindings)>();
+    let bindings: Vec<(Entity, MaterialBindings)> = query
+        .iter(world)
+        .map(|(e, bindings)| (e, bindings.clone()))
+        .collect();
+    for (e, bindings) in bindings {
+        for binding in &bindings.0 {
+            binding(world, e);
+        }
+    }
+}
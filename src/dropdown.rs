@@ -0,0 +1,180 @@
+use std::sync::Arc;
+
+use bevy::{
+    ecs::{
+        component::Component,
+        entity::Entity,
+        hierarchy::{ChildOf, Children},
+        observer::On,
+        query::{Changed, With, Without},
+        relationship::RelatedSpawnerCommands,
+        system::{Commands, EntityCommands, Query},
+    },
+    ui::{
+        BackgroundColor, Display, FlexDirection, Node, PositionType, UiRect, percent, px,
+        widget::Text as UiText,
+    },
+    ui_widgets::{Activate, Button as UiButton, ValueChange},
+};
+
+use crate::{
+    Element, Text, TextSizing, UiContext,
+    layer::{LayerZIndex, UiLayer},
+};
+
+pub struct Dropdown {
+    pub options: Vec<String>,
+    pub selected: usize,
+}
+
+impl Dropdown {
+    #[inline]
+    pub fn new<T: Into<String>, I: IntoIterator<Item = T>>(options: I) -> Self {
+        Self {
+            options: options.into_iter().map(Into::into).collect(),
+            selected: 0,
+        }
+    }
+
+    #[inline]
+    pub fn with_selected(mut self, selected: usize) -> Self {
+        self.selected = selected;
+        self
+    }
+}
+
+#[derive(Component)]
+pub struct DropdownState {
+    pub options: Vec<String>,
+    pub selected: usize,
+    pub open: bool,
+}
+
+impl DropdownState {
+    #[inline]
+    pub fn selected_option(&self) -> Option<&str> {
+        self.options.get(self.selected).map(String::as_str)
+    }
+}
+
+#[derive(Component)]
+pub struct DropdownLabel;
+
+#[derive(Component)]
+pub struct DropdownList;
+
+#[derive(Component)]
+pub struct DropdownOption {
+    pub dropdown: Entity,
+    pub index: usize,
+}
+
+impl Element for Dropdown {
+    type Bundle = (DropdownState, UiButton);
+
+    #[inline]
+    fn create_bundle(&self, _context: &UiContext) -> Self::Bundle {
+        (
+            DropdownState {
+                options: self.options.clone(),
+                selected: self.selected,
+                open: false,
+            },
+            UiButton,
+        )
+    }
+
+    #[inline]
+    fn register_observers(&self, entity_command: &mut EntityCommands, _context: &UiContext) {
+        entity_command.observe(toggle_dropdown);
+    }
+
+    #[inline]
+    fn spawn_children(&self, rcs: &mut RelatedSpawnerCommands<ChildOf>, context: Arc<UiContext>) {
+        let dropdown = rcs.target_entity();
+        let text = |text: &str| Text::new(text, TextSizing::Small).create_bundle(&context);
+        let label = self
+            .options
+            .get(self.selected)
+            .map(String::as_str)
+            .unwrap_or_default();
+        rcs.spawn((Node::default(), DropdownLabel, text(label)));
+        rcs.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: percent(100),
+                left: px(0),
+                flex_direction: FlexDirection::Column,
+                display: Display::None,
+                ..Default::default()
+            },
+            DropdownList,
+            BackgroundColor(context.background_color),
+            LayerZIndex(UiLayer::Popup),
+        ))
+        .with_children(|list| {
+            for (index, option) in self.options.iter().enumerate() {
+                list.spawn((
+                    Node {
+                        padding: UiRect::all(px(2)),
+                        ..Default::default()
+                    },
+                    UiButton,
+                    DropdownOption { dropdown, index },
+                    text(option),
+                ))
+                .observe(select_dropdown_option);
+            }
+        });
+    }
+
+    #[inline]
+    fn modify_node(&self, _node: &mut Node, _context: &UiContext) {}
+}
+
+fn toggle_dropdown(on: On<Activate>, mut query: Query<&mut DropdownState>) {
+    if let Ok(mut state) = query.get_mut(on.entity) {
+        state.open = !state.open;
+    }
+}
+
+fn select_dropdown_option(
+    on: On<Activate>,
+    options: Query<&DropdownOption>,
+    mut states: Query<&mut DropdownState>,
+    mut commands: Commands,
+) {
+    let Ok(option) = options.get(on.entity) else {
+        return;
+    };
+    let Ok(mut state) = states.get_mut(option.dropdown) else {
+        return;
+    };
+    state.selected = option.index;
+    state.open = false;
+    commands.trigger(ValueChange {
+        source: option.dropdown,
+        value: option.index,
+    });
+}
+
+pub(crate) fn update_dropdown(
+    dropdowns: Query<(&DropdownState, &Children), Changed<DropdownState>>,
+    mut labels: Query<&mut UiText, With<DropdownLabel>>,
+    mut lists: Query<&mut Node, (With<DropdownList>, Without<DropdownLabel>)>,
+) {
+    for (state, children) in dropdowns {
+        for child in children.iter() {
+            if let Ok(mut label) = labels.get_mut(*child) {
+                label.0 = state.selected_option().unwrap_or_default().to_string();
+            }
+            if let Ok(mut list) = lists.get_mut(*child) {
+                list.display = if state.open {
+                    Display::Flex
+                } else {
+                    Display::None
+                };
+            }
+        }
+    }
+}
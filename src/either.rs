@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use bevy::{
+    ecs::{hierarchy::ChildOf, relationship::RelatedSpawnerCommands, system::EntityCommands},
+    ui::Node,
+};
+
+use crate::{Element, UiContext};
+
+pub enum Either<A: Element, B: Element> {
+    Left(A),
+    Right(B),
+}
+
+impl<A: Element, B: Element> Element for Either<A, B> {
+    type Bundle = ();
+
+    #[inline]
+    fn modify_node(&self, _node: &mut Node, _context: &UiContext) {}
+
+    #[inline]
+    fn create_bundle(&self, _context: &UiContext) -> Self::Bundle {}
+
+    #[inline]
+    fn register_observers(&self, _entity_command: &mut EntityCommands, _context: &UiContext) {}
+
+    #[inline]
+    fn spawn_children(&self, rcs: &mut RelatedSpawnerCommands<ChildOf>, context: Arc<UiContext>) {
+        self.spawn_as_child(rcs, context);
+    }
+
+    #[inline]
+    fn spawn_as_child(&self, rcs: &mut RelatedSpawnerCommands<ChildOf>, context: Arc<UiContext>) {
+        match self {
+            Either::Left(a) => a.spawn_as_child(rcs, context),
+            Either::Right(b) => b.spawn_as_child(rcs, context),
+        }
+    }
+}
+
+impl<E: Element> Element for Option<E> {
+    type Bundle = ();
+
+    #[inline]
+    fn modify_node(&self, _node: &mut Node, _context: &UiContext) {}
+
+    #[inline]
+    fn create_bundle(&self, _context: &UiContext) -> Self::Bundle {}
+
+    #[inline]
+    fn register_observers(&self, _entity_command: &mut EntityCommands, _context: &UiContext) {}
+
+    #[inline]
+    fn spawn_children(&self, rcs: &mut RelatedSpawnerCommands<ChildOf>, context: Arc<UiContext>) {
+        self.spawn_as_child(rcs, context);
+    }
+
+    #[inline]
+    fn spawn_as_child(&self, rcs: &mut RelatedSpawnerCommands<ChildOf>, context: Arc<UiContext>) {
+        if let Some(e) = self {
+            e.spawn_as_child(rcs, context);
+        }
+    }
+}
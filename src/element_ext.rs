@@ -0,0 +1,218 @@
+use bevy::{
+    ecs::{
+        component::{Component, Mutable},
+        entity::Entity,
+        event::EntityEvent,
+        lifecycle::Insert,
+        resource::Resource,
+        system::IntoObserverSystem,
+    },
+    math::Vec2,
+    picking::hover::Hovered,
+    ui::{AlignItems, BoxSizing, JustifyContent, Node, UiRect, Val},
+};
+
+use crate::{
+    Element,
+    absolute::Absolute,
+    aligned::Aligned,
+    background::Background,
+    binding::{Binding, BindingValue, Bound},
+    border::Border,
+    centered::Centered,
+    gapped::Gapped,
+    grid::Grid,
+    hover::Hover,
+    inject::ResourceInjected,
+    justified::Justified,
+    layer::{Layered, UiLayer},
+    margin::Margin,
+    on_event::OnEvent,
+    padded::Padded,
+    panel::Panel,
+    positioned::Positioned,
+    responsive::{Breakpoint, ResponsiveNode},
+    scaled::Scale,
+    sized::Sized,
+    sizing::Sizing,
+    theme::Theme,
+    world_anchor::WorldAnchored,
+};
+
+pub trait ElementExt: Element + core::marker::Sized {
+    #[inline]
+    fn padded(self, padding: UiRect) -> Padded<Self> {
+        Padded {
+            content: self,
+            padding,
+        }
+    }
+
+    #[inline]
+    fn margin(self, margin: UiRect) -> Margin<Self> {
+        Margin {
+            content: self,
+            margin,
+        }
+    }
+
+    #[inline]
+    fn centered(self) -> Centered<Self> {
+        Centered { content: self }
+    }
+
+    #[inline]
+    fn aligned(self, align_items: AlignItems) -> Aligned<Self> {
+        Aligned {
+            content: self,
+            align_items,
+        }
+    }
+
+    #[inline]
+    fn justified(self, justify_content: JustifyContent) -> Justified<Self> {
+        Justified {
+            content: self,
+            justify_content,
+        }
+    }
+
+    #[inline]
+    fn gapped(self, gap: Val) -> Gapped<Self> {
+        Gapped::new(self).with_value(gap)
+    }
+
+    #[inline]
+    fn grid(self) -> Grid<Self> {
+        Grid { content: self }
+    }
+
+    #[inline]
+    fn sized(self, width: Val, height: Val) -> Sized<Self> {
+        Sized::new(width, height, self)
+    }
+
+    #[inline]
+    fn expanded(self) -> Sized<Self> {
+        Sized::expanded(self)
+    }
+
+    #[inline]
+    fn box_sizing(self, sizing: BoxSizing) -> Sizing<Self> {
+        Sizing {
+            content: self,
+            sizing,
+        }
+    }
+
+    #[inline]
+    fn bordered(self) -> Border<Self> {
+        Border::all(self)
+    }
+
+    #[inline]
+    fn background(self) -> Background<Self> {
+        Background::new(self)
+    }
+
+    #[inline]
+    fn panel(self) -> Panel<Self> {
+        Panel::new(self)
+    }
+
+    #[inline]
+    fn themed<F: FnOnce(Theme<Self>) -> Theme<Self>>(self, theme: F) -> Theme<Self> {
+        theme(Theme::new(self))
+    }
+
+    #[inline]
+    fn absolute(self) -> Absolute<Self> {
+        Absolute::new(self)
+    }
+
+    #[inline]
+    fn positioned(self, left: Val, right: Val, top: Val, bottom: Val) -> Positioned<Self> {
+        Positioned {
+            left,
+            right,
+            top,
+            bottom,
+            content: self,
+        }
+    }
+
+    #[inline]
+    fn layered(self, layer: UiLayer) -> Layered<Self> {
+        Layered::new(layer, self)
+    }
+
+    #[inline]
+    fn world_anchored(self, target: Entity) -> WorldAnchored<Self> {
+        WorldAnchored::new(target, self)
+    }
+
+    #[inline]
+    fn responsive<F>(self, breakpoint: Breakpoint, modify: F) -> ResponsiveNode<Self>
+    where
+        F: Fn(&mut Node) + Send + Sync + 'static,
+    {
+        ResponsiveNode::new(self).with_breakpoint(breakpoint, modify)
+    }
+
+    #[inline]
+    fn scaled(self, scale: Vec2) -> Scale<Self> {
+        Scale {
+            scale,
+            content: self,
+        }
+    }
+
+    #[inline]
+    fn bind<T: BindingValue>(self, binding: Binding<T>) -> Bound<Self, T> {
+        Bound::new(binding, self)
+    }
+
+    #[inline]
+    fn inject<R: Resource, C: Component<Mutability = Mutable>, F>(
+        self,
+        inject: F,
+    ) -> ResourceInjected<Self>
+    where
+        F: Fn(&R, &mut C) + Send + Sync + 'static,
+    {
+        ResourceInjected::new(self).inject(inject)
+    }
+
+    #[inline]
+    fn inject_synced<R: Resource, C: Component<Mutability = Mutable>, F>(
+        self,
+        inject: F,
+    ) -> ResourceInjected<Self>
+    where
+        F: Fn(&R, &mut C) + Send + Sync + 'static,
+    {
+        ResourceInjected::new(self).inject_synced(inject)
+    }
+
+    #[inline]
+    fn hover<F, M: 'static>(self, on_hover: F) -> Hover<Self>
+    where
+        F: IntoObserverSystem<Insert, Hovered, M> + Copy + Send + Sync,
+    {
+        Hover::new(on_hover, self)
+    }
+}
+
+impl<E: Element> ElementExt for E {}
+
+pub trait OnEventExt<M: 'static>: Element + core::marker::Sized {
+    #[inline]
+    fn on_event<Ev: EntityEvent>(
+        self,
+        on_event: impl IntoObserverSystem<Ev, (), M> + Copy + Sync,
+    ) -> OnEvent<Self, Ev> {
+        OnEvent::new(on_event, self)
+    }
+}
+
+impl<E: Element, M: 'static> OnEventExt<M> for E {}
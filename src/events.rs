@@ -4,3 +4,10 @@ use bevy::ecs::{entity::Entity, event::EntityEvent};
 pub struct Init {
     pub entity: Entity,
 }
+
+#[derive(EntityEvent)]
+pub struct ImageLoadFailed {
+    pub entity: Entity,
+    pub path: String,
+    pub error: String,
+}
@@ -1,24 +1,36 @@
 use std::{ops::Deref, sync::Arc};
 
 use bevy::{
-    asset::Handle,
+    asset::{AssetServer, Handle, LoadState},
+    color::Color,
     ecs::{
+        component::Component,
+        entity::Entity,
         hierarchy::ChildOf,
         observer::On,
+        query::Added,
         relationship::RelatedSpawnerCommands,
         resource::Resource,
-        system::{EntityCommands, Query, Res},
+        system::{Commands, EntityCommands, Query, Res},
     },
-    log::error,
+    image::{TextureAtlas, TextureAtlasLayout},
+    log::{error, warn},
     math::Rect,
     prelude::Image as UiImage,
     ui::{
         Node,
+        prelude::{BorderRect, TextureSlicer},
         widget::{ImageNode, NodeImageMode},
     },
 };
 
-use crate::{Element, IntoChild, UiContext, child::Child, events::Init, on_event::OnEvent};
+use crate::{
+    Element, IntoChild, UiContext,
+    atlas_animation::AtlasAnimated,
+    child::Child,
+    events::{ImageLoadFailed, Init},
+    on_event::OnEvent,
+};
 
 pub type ImageFromResource<E> = OnEvent<Image<E>, Init>;
 
@@ -47,6 +59,9 @@ pub struct Image<E: Element> {
     pub handle: Handle<UiImage>,
     pub rect: Option<Rect>,
     pub image_mode: NodeImageMode,
+    pub atlas: Option<TextureAtlas>,
+    pub flip_x: bool,
+    pub flip_y: bool,
 }
 impl Image<()> {
     #[inline]
@@ -56,6 +71,9 @@ impl Image<()> {
             handle: Handle::default(),
             rect: None,
             image_mode: NodeImageMode::default(),
+            atlas: None,
+            flip_x: false,
+            flip_y: false,
         }
     }
     #[inline]
@@ -65,8 +83,56 @@ impl Image<()> {
             handle,
             rect: None,
             image_mode: NodeImageMode::Auto,
+            atlas: None,
+            flip_x: false,
+            flip_y: false,
+        }
+    }
+
+    #[inline]
+    pub fn from_path<P: Into<String>>(path: P) -> ImageFromPath<()> {
+        ImageFromPath {
+            image: Self::new(),
+            path: path.into(),
+            placeholder: None,
         }
     }
+
+    #[inline]
+    pub fn from_atlas(
+        handle: Handle<UiImage>,
+        layout: Handle<TextureAtlasLayout>,
+        index: usize,
+    ) -> Self {
+        Self::new_with_handle(handle).with_atlas(layout, index)
+    }
+
+    #[inline]
+    pub fn sliced(handle: Handle<UiImage>, insets: BorderRect) -> Self {
+        Self::new_with_handle(handle).with_image_mode(sliced_mode(insets))
+    }
+
+    #[inline]
+    pub fn tiled(handle: Handle<UiImage>, stretch_value: f32) -> Self {
+        Self::new_with_handle(handle).with_image_mode(tiled_mode(stretch_value))
+    }
+}
+
+#[inline]
+pub fn sliced_mode(insets: BorderRect) -> NodeImageMode {
+    NodeImageMode::Sliced(TextureSlicer {
+        border: insets,
+        ..Default::default()
+    })
+}
+
+#[inline]
+pub fn tiled_mode(stretch_value: f32) -> NodeImageMode {
+    NodeImageMode::Tiled {
+        tile_x: true,
+        tile_y: true,
+        stretch_value,
+    }
 }
 
 impl Default for Image<()> {
@@ -83,6 +149,9 @@ impl<E: Element> Image<E> {
             handle: self.handle,
             rect: self.rect,
             image_mode: self.image_mode,
+            atlas: self.atlas,
+            flip_x: self.flip_x,
+            flip_y: self.flip_y,
         }
     }
 
@@ -93,9 +162,42 @@ impl<E: Element> Image<E> {
             handle: self.handle,
             rect: self.rect,
             image_mode: self.image_mode,
+            atlas: self.atlas,
+            flip_x: self.flip_x,
+            flip_y: self.flip_y,
         }
     }
 
+    #[inline]
+    pub fn with_atlas(mut self, layout: Handle<TextureAtlasLayout>, index: usize) -> Self {
+        self.atlas = Some(TextureAtlas { layout, index });
+        self
+    }
+
+    #[inline]
+    pub fn flipped(mut self, flip_x: bool, flip_y: bool) -> Self {
+        self.flip_x = flip_x;
+        self.flip_y = flip_y;
+        self
+    }
+
+    #[inline]
+    pub fn animated(self, first: usize, last: usize, fps: f32) -> AtlasAnimated<Self> {
+        AtlasAnimated::new(self, first, last, fps)
+    }
+
+    #[inline]
+    pub fn with_image_mode(mut self, image_mode: NodeImageMode) -> Self {
+        self.image_mode = image_mode;
+        self
+    }
+
+    #[inline]
+    pub fn with_rect(mut self, rect: Rect) -> Self {
+        self.rect = Some(rect);
+        self
+    }
+
     #[inline]
     pub fn set_image(&mut self, handle: Handle<UiImage>) {
         self.handle = handle;
@@ -111,9 +213,9 @@ impl<E: Element> Element for Image<E> {
             ImageNode {
                 color: context.image_color,
                 image: self.handle.clone(),
-                texture_atlas: None,
-                flip_x: false,
-                flip_y: false,
+                texture_atlas: self.atlas.clone(),
+                flip_x: self.flip_x,
+                flip_y: self.flip_y,
                 rect: self.rect,
                 image_mode: self.image_mode.clone(),
             },
@@ -137,6 +239,108 @@ impl<E: Element> Element for Image<E> {
     }
 }
 
+pub struct ImageFromPath<E: Element> {
+    pub image: Image<E>,
+    pub path: String,
+    pub placeholder: Option<Color>,
+}
+
+impl<E: Element> ImageFromPath<E> {
+    #[inline]
+    pub fn with_placeholder(mut self, color: Color) -> Self {
+        self.placeholder = Some(color);
+        self
+    }
+
+    #[inline]
+    pub fn with_image<O: Element, F: FnOnce(Image<E>) -> Image<O>>(self, f: F) -> ImageFromPath<O> {
+        ImageFromPath {
+            image: f(self.image),
+            path: self.path,
+            placeholder: self.placeholder,
+        }
+    }
+
+    #[inline]
+    pub fn with_content<O: Element>(self, content: O) -> ImageFromPath<O> {
+        self.with_image(|image| image.with_content(content))
+    }
+}
+
+#[derive(Component)]
+pub struct ImagePath {
+    pub path: String,
+    pub color: Color,
+}
+
+#[derive(Component)]
+pub struct ImageLoading(pub Handle<UiImage>);
+
+impl<E: Element> Element for ImageFromPath<E> {
+    type Bundle = (ImagePath, <Image<E> as Element>::Bundle);
+
+    #[inline]
+    fn create_bundle(&self, context: &UiContext) -> Self::Bundle {
+        let (mut image_node, bundle) = self.image.create_bundle(context);
+        image_node.image = Handle::default();
+        image_node.color = self.placeholder.unwrap_or(context.background_color);
+        (
+            ImagePath {
+                path: self.path.clone(),
+                color: context.image_color,
+            },
+            (image_node, bundle),
+        )
+    }
+
+    #[inline]
+    fn register_observers(&self, entity_commands: &mut EntityCommands, context: &UiContext) {
+        self.image.register_observers(entity_commands, context);
+    }
+
+    #[inline]
+    fn spawn_children(&self, rcs: &mut RelatedSpawnerCommands<ChildOf>, context: Arc<UiContext>) {
+        self.image.spawn_children(rcs, context);
+    }
+
+    #[inline]
+    fn modify_node(&self, node: &mut Node, context: &UiContext) {
+        self.image.modify_node(node, context);
+    }
+}
+
+pub(crate) fn load_image_paths(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    added: Query<(Entity, &ImagePath), Added<ImagePath>>,
+    loading: Query<(Entity, &ImagePath, &ImageLoading, &mut ImageNode)>,
+) {
+    for (e, path) in added {
+        commands
+            .entity(e)
+            .insert(ImageLoading(asset_server.load(&path.path)));
+    }
+    for (e, path, handle, mut image_node) in loading {
+        match asset_server.get_load_state(&handle.0) {
+            Some(LoadState::Loaded) => {
+                image_node.image = handle.0.clone();
+                image_node.color = path.color;
+                commands.entity(e).remove::<ImageLoading>();
+            }
+            Some(LoadState::Failed(err)) => {
+                warn!("failed to load image {}: {}", path.path, err);
+                commands.entity(e).remove::<ImageLoading>();
+                commands.trigger(ImageLoadFailed {
+                    entity: e,
+                    path: path.path.clone(),
+                    error: err.to_string(),
+                });
+            }
+            _ => {}
+        }
+    }
+}
+
 pub fn init_image_from_resource<R: Resource + Deref<Target = Handle<bevy::prelude::Image>>>(
     on: On<Init>,
     mut query: Query<&mut ImageNode>,
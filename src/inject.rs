@@ -0,0 +1,166 @@
+use std::sync::Arc;
+
+use bevy::{
+    ecs::{
+        component::{Component, Mutable},
+        entity::Entity,
+        hierarchy::ChildOf,
+        observer::On,
+        relationship::RelatedSpawnerCommands,
+        resource::Resource,
+        system::{Commands, EntityCommands, Query},
+        world::{Mut, World},
+    },
+    ui::Node,
+};
+
+use crate::{Element, UiContext, events::Init};
+
+type ApplyFn = Arc<dyn Fn(&mut World, Entity) + Send + Sync>;
+type ChangedFn = Arc<dyn Fn(&World) -> bool + Send + Sync>;
+
+#[derive(Clone)]
+pub struct Injection {
+    apply: ApplyFn,
+    changed: Option<ChangedFn>,
+}
+
+impl Injection {
+    #[inline]
+    pub fn new<R: Resource, C: Component<Mutability = Mutable>, F>(inject: F) -> Self
+    where
+        F: Fn(&R, &mut C) + Send + Sync + 'static,
+    {
+        Self {
+            apply: Arc::new(move |world, entity| {
+                world.try_resource_scope(|world, resource: Mut<R>| {
+                    if let Some(mut component) = world.get_mut::<C>(entity) {
+                        inject(&resource, &mut component);
+                    }
+                });
+            }),
+            changed: None,
+        }
+    }
+
+    #[inline]
+    pub fn synced<R: Resource, C: Component<Mutability = Mutable>, F>(inject: F) -> Self
+    where
+        F: Fn(&R, &mut C) + Send + Sync + 'static,
+    {
+        Self {
+            changed: Some(Arc::new(|world| world.is_resource_changed::<R>())),
+            ..Self::new(inject)
+        }
+    }
+
+    #[inline]
+    pub fn apply(&self, world: &mut World, entity: Entity) {
+        (self.apply)(world, entity)
+    }
+}
+
+#[derive(Component, Clone)]
+pub struct ResourceInjections(pub Vec<Injection>);
+
+pub struct ResourceInjected<E: Element> {
+    pub content: E,
+    pub injections: Vec<Injection>,
+}
+
+impl<E: Element> ResourceInjected<E> {
+    #[inline]
+    pub fn new(content: E) -> Self {
+        Self {
+            content,
+            injections: vec![],
+        }
+    }
+
+    #[inline]
+    pub fn with_injection(mut self, injection: Injection) -> Self {
+        self.injections.push(injection);
+        self
+    }
+
+    #[inline]
+    pub fn inject<R: Resource, C: Component<Mutability = Mutable>, F>(self, inject: F) -> Self
+    where
+        F: Fn(&R, &mut C) + Send + Sync + 'static,
+    {
+        self.with_injection(Injection::new(inject))
+    }
+
+    #[inline]
+    pub fn inject_synced<R: Resource, C: Component<Mutability = Mutable>, F>(
+        self,
+        inject: F,
+    ) -> Self
+    where
+        F: Fn(&R, &mut C) + Send + Sync + 'static,
+    {
+        self.with_injection(Injection::synced(inject))
+    }
+}
+
+impl<E: Element> Element for ResourceInjected<E> {
+    type Bundle = (ResourceInjections, E::Bundle);
+
+    #[inline]
+    fn modify_node(&self, node: &mut Node, context: &UiContext) {
+        self.content.modify_node(node, context);
+    }
+
+    #[inline]
+    fn create_bundle(&self, context: &UiContext) -> Self::Bundle {
+        (
+            ResourceInjections(self.injections.clone()),
+            self.content.create_bundle(context),
+        )
+    }
+
+    #[inline]
+    fn register_observers(&self, entity_command: &mut EntityCommands, context: &UiContext) {
+        self.content.register_observers(entity_command, context);
+        entity_command.observe(init_resource_injections);
+    }
+
+    #[inline]
+    fn spawn_children(&self, rcs: &mut RelatedSpawnerCommands<ChildOf>, context: Arc<UiContext>) {
+        self.content.spawn_children(rcs, context);
+    }
+}
+
+fn init_resource_injections(
+    on: On<Init>,
+    query: Query<&ResourceInjections>,
+    mut commands: Commands,
+) {
+    let Ok(injections) = query.get(on.entity) else {
+        return;
+    };
+    let entity = on.entity;
+    let injections = injections.0.clone();
+    commands.queue(move |world: &mut World| {
+        for injection in &injections {
+            injection.apply(world, entity);
+        }
+    });
+}
+
+pub(crate) fn sync_resource_injections(world: &mut World) {
+    let mut query = world.query::<(Entity, &ResourceInjections)>();
+    let changed: Vec<(Entity, Injection)> = query
+        .iter(world)
+        .flat_map(|(e, injections)| {
+            injections
+                .0
+                .iter()
+                .filter(|i| i.changed.as_ref().is_some_and(|changed| changed(world)))
+                .map(move |i| (e, i.clone()))
+        })
+        .collect();
+    for (e, injection) in changed {
+        injection.apply(world, e);
+    }
+}
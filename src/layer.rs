@@ -0,0 +1,205 @@
+use std::sync::Arc;
+
+use bevy::{
+    ecs::{
+        change_detection::{DetectChanges, DetectChangesMut},
+        component::Component,
+        entity::Entity,
+        hierarchy::ChildOf,
+        lifecycle::Remove,
+        observer::On,
+        query::{AnyOf, Without},
+        relationship::RelatedSpawnerCommands,
+        resource::Resource,
+        system::{Commands, EntityCommands, Query, Res, ResMut},
+        world::Ref,
+    },
+    log::info,
+    picking::Pickable,
+    platform::collections::HashMap,
+    prelude::{Deref, DerefMut},
+    ui::{GlobalZIndex, Node, PositionType, UiTargetCamera, percent},
+};
+
+use crate::{Element, MenuCamera, UiContext};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum UiLayer {
+    Background,
+    Hud,
+    Menu,
+    Popup,
+    Tooltip,
+    Debug,
+    Custom(&'static str),
+}
+
+#[derive(Resource, Clone, Debug)]
+pub struct UiLayers {
+    z_indices: HashMap<UiLayer, i32>,
+}
+
+impl Default for UiLayers {
+    fn default() -> Self {
+        Self {
+            z_indices: HashMap::from_iter([
+                (UiLayer::Background, -100),
+                (UiLayer::Hud, 0),
+                (UiLayer::Menu, 100),
+                (UiLayer::Popup, 200),
+                (UiLayer::Tooltip, 300),
+                (UiLayer::Debug, 1000),
+            ]),
+        }
+    }
+}
+
+impl UiLayers {
+    #[inline]
+    pub fn with_layer(mut self, layer: UiLayer, z_index: i32) -> Self {
+        self.set_layer(layer, z_index);
+        self
+    }
+
+    #[inline]
+    pub fn set_layer(&mut self, layer: UiLayer, z_index: i32) {
+        self.z_indices.insert(layer, z_index);
+    }
+
+    #[inline]
+    pub fn z_index(&self, layer: UiLayer) -> i32 {
+        self.z_indices
+            .get(&layer)
+            .or_else(|| self.z_indices.get(&UiLayer::Menu))
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct LayerTarget(pub UiLayer);
+
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct LayerRoot(pub UiLayer);
+
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+#[require(GlobalZIndex)]
+pub struct LayerZIndex(pub UiLayer);
+
+#[derive(Component)]
+#[relationship(relationship_target = LayeredContents)]
+pub struct LayerOwner(pub Entity);
+
+#[derive(Component)]
+#[relationship_target(relationship = LayerOwner, linked_spawn)]
+pub struct LayeredContents(Vec<Entity>);
+
+#[derive(Resource, Deref, DerefMut, Default)]
+pub(crate) struct LayerRoots(HashMap<(UiLayer, Option<Entity>), Entity>);
+
+pub struct Layered<E: Element> {
+    pub content: E,
+    pub layer: UiLayer,
+}
+
+impl<E: Element> Layered<E> {
+    #[inline]
+    pub fn new(layer: UiLayer, content: E) -> Self {
+        Self { content, layer }
+    }
+}
+
+impl<E: Element> Element for Layered<E> {
+    type Bundle = (LayerTarget, E::Bundle);
+
+    #[inline]
+    fn modify_node(&self, node: &mut Node, context: &UiContext) {
+        self.content.modify_node(node, context);
+    }
+
+    #[inline]
+    fn create_bundle(&self, context: &UiContext) -> Self::Bundle {
+        (LayerTarget(self.layer), self.content.create_bundle(context))
+    }
+
+    #[inline]
+    fn register_observers(&self, entity_command: &mut EntityCommands, context: &UiContext) {
+        self.content.register_observers(entity_command, context);
+    }
+
+    #[inline]
+    fn spawn_children(&self, rcs: &mut RelatedSpawnerCommands<ChildOf>, context: Arc<UiContext>) {
+        self.content.spawn_children(rcs, context);
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn move_to_layer(
+    mut commands: Commands,
+    mut roots: ResMut<LayerRoots>,
+    layers: Res<UiLayers>,
+    query: Query<
+        (Entity, &LayerTarget, Option<&ChildOf>),
+        (Without<LayerOwner>, Without<LayerRoot>),
+    >,
+    parents: Query<&ChildOf>,
+    cameras: Query<AnyOf<(&MenuCamera, &UiTargetCamera)>>,
+) {
+    for (e, target, parent) in query {
+        let layer = target.0;
+        let Some(parent) = parent else {
+            commands
+                .entity(e)
+                .insert((LayerRoot(layer), GlobalZIndex(layers.z_index(layer))));
+            continue;
+        };
+        let camera = parents
+            .iter_ancestors(e)
+            .last()
+            .and_then(|root| cameras.get(root).ok())
+            .and_then(|(menu, target)| menu.map(|c| c.0).or(target.map(|c| c.0)));
+        let root = *roots.entry((layer, camera)).or_insert_with(|| {
+            info!("spawning ui layer root: {:?}", layer);
+            let mut ec = commands.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    width: percent(100.0),
+                    height: percent(100.0),
+                    ..Default::default()
+                },
+                LayerRoot(layer),
+                GlobalZIndex(layers.z_index(layer)),
+                Pickable::IGNORE,
+            ));
+            if let Some(camera) = camera {
+                ec.insert(UiTargetCamera(camera));
+            }
+            ec.id()
+        });
+        commands
+            .entity(e)
+            .insert((ChildOf(root), LayerOwner(parent.parent())));
+    }
+}
+
+pub(crate) fn sync_layer_z_index(
+    layers: Res<UiLayers>,
+    roots: Query<(&LayerRoot, &mut GlobalZIndex), Without<LayerZIndex>>,
+    stacked: Query<(Ref<LayerZIndex>, &mut GlobalZIndex), Without<LayerRoot>>,
+) {
+    for (stack, mut z_index) in stacked {
+        if layers.is_changed() || stack.is_changed() {
+            z_index.set_if_neq(GlobalZIndex(layers.z_index(stack.0)));
+        }
+    }
+    if !layers.is_changed() {
+        return;
+    }
+    for (root, mut z_index) in roots {
+        z_index.set_if_neq(GlobalZIndex(layers.z_index(root.0)));
+    }
+}
+
+pub(crate) fn remove_layer_root(on: On<Remove, LayerRoot>, mut roots: ResMut<LayerRoots>) {
+    roots.retain(|_, root| *root != on.entity);
+}
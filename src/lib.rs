@@ -6,30 +6,49 @@ pub mod slider;
 pub mod absolute;
 pub mod aligned;
 pub mod animated;
+pub mod atlas_animation;
 pub mod background;
+pub mod binding;
 pub mod border;
+pub mod bound_text;
 pub mod centered;
 pub mod checkbox;
 pub mod child;
 pub mod custom_material;
+pub mod dropdown;
+pub mod either;
+pub mod element_ext;
 pub mod events;
 pub mod gapped;
 pub mod grid;
 pub mod hover;
 pub mod image;
+pub mod inject;
 pub mod justified;
+pub mod layer;
+pub mod localization;
 pub mod margin;
 pub mod on_event;
 pub mod padded;
+pub mod panel;
 pub mod placeholder;
 pub mod positioned;
+pub mod responsive;
+pub mod rich_text;
 pub mod scaled;
+pub mod settings;
 pub mod sibling;
 pub mod sizing;
+pub mod switch;
 pub mod text;
+pub mod text_input;
 pub mod theme;
+pub mod typography;
+pub mod ui_scaling;
+pub mod world_anchor;
 
 pub use button::Button;
+pub use element_ext::{ElementExt, OnEventExt};
 pub use group::Column;
 pub use slider::Slider;
 pub use text::{Text, TextSizing};
@@ -37,70 +56,163 @@ pub use text::{Text, TextSizing};
 use std::{marker::PhantomData, sync::Arc};
 
 use bevy::{
-    app::{App, Plugin, Update},
-    asset::Handle,
+    app::{App, Plugin, PostUpdate, Update},
+    asset::{AssetServer, Handle},
+    camera::Camera,
     color::Color,
     ecs::{
         bundle::Bundle,
         change_detection::{DetectChanges, DetectChangesMut},
         component::Component,
-        entity::{Entity, EntityIndexSet},
-        hierarchy::ChildOf,
-        message::{Message, MessageReader},
-        query::Added,
-        relationship::RelatedSpawnerCommands,
+        entity::{Entity, EntityHashSet, EntityIndexSet},
+        hierarchy::{ChildOf, Children},
+        lifecycle::{Remove, RemovedComponents},
+        message::{Message, MessageReader, MessageWriter},
+        observer::{ObservedBy, On},
+        query::{Added, Changed, With, Without},
+        relationship::{RelatedSpawnerCommands, RelationshipTarget},
         resource::Resource,
         schedule::{
             IntoScheduleConfigs, SystemSet,
             common_conditions::{resource_added, resource_exists},
         },
         system::{Commands, EntityCommands, Query, Res, ResMut},
-        world::{FromWorld, Ref},
+        world::{EntityRef, FromWorld, Ref, World},
     },
     log::{info, warn},
     platform::collections::HashMap,
-    prelude::{Deref, DerefMut},
-    text::Font,
-    ui::{BorderColor, BorderRadius, Node, UiRect, px},
+    state::state::{OnEnter, OnExit, States},
+    text::{Font, Justify, LineBreak, LineHeight},
+    time::Time,
+    transform::TransformSystems,
+    ui::{BorderColor, BorderRadius, Node, UiRect, UiSystems as BevyUiSystems, UiTargetCamera, px},
 };
 
 use crate::{
+    atlas_animation::animate_atlas,
+    binding::sync_bound_values,
+    bound_text::update_bound_text,
     centered::Centered,
     checkbox::update_checkbox_style,
     child::Child,
+    custom_material::sync_material_bindings,
+    dropdown::update_dropdown,
     events::Init,
-    placeholder::{InsertPlaceholderTraget, PlaceholderTarget},
+    image::load_image_paths,
+    inject::sync_resource_injections,
+    layer::{LayerRoots, UiLayers, move_to_layer, remove_layer_root, sync_layer_z_index},
+    panel::{PanelStyle, remove_panel_image},
+    placeholder::{
+        PlaceHolders, detach_placeholder_content, move_to_placeholder_target, remove_placeholder,
+        update_placeholder,
+    },
+    responsive::update_responsive_nodes,
     scaled::update_computed_size,
     sized::update_node_on_size_change,
     slider::update_slider_style,
+    switch::update_switches,
+    text::truncate_text_lines,
+    text_input::{update_text_input, update_text_input_text},
     theme::Themed,
+    typography::Typography,
+    ui_scaling::{UiScaling, apply_text_scale, apply_ui_scale, track_base_font_size},
+    world_anchor::update_world_anchors,
 };
 
+type AppConfiguration = Arc<dyn Fn(&mut App) + Send + Sync>;
+
 pub struct MenuPlugin<M: Component> {
     _pd: PhantomData<M>,
     root: Root,
+    state_binding: Option<AppConfiguration>,
+    camera_binding: Option<AppConfiguration>,
 }
 impl<M: Component> Default for MenuPlugin<M> {
     fn default() -> Self {
         Self {
             _pd: Default::default(),
             root: Default::default(),
+            state_binding: None,
+            camera_binding: None,
         }
     }
 }
 
+type RootSpawner = Arc<Box<dyn ChildElementSpawner>>;
+type RootBuilder = Arc<dyn Fn(EntityRef) -> RootSpawner + Send + Sync>;
+
+fn static_root<E: IntoChildElementSpawner>(element: E) -> RootBuilder {
+    let spawner: RootSpawner = Arc::new(element.into_element_spawner());
+    Arc::new(move |_| spawner.clone())
+}
+
+fn built_root<F, E>(builder: F) -> RootBuilder
+where
+    F: Fn(EntityRef) -> E + Send + Sync + 'static,
+    E: IntoChildElementSpawner,
+{
+    Arc::new(move |entity| Arc::new(builder(entity).into_element_spawner()))
+}
+
 #[derive(Clone)]
 pub struct Root {
-    root_element: Arc<Box<dyn ChildElementSpawner>>,
+    root_element: RootBuilder,
+    variants: HashMap<String, RootBuilder>,
 }
 
 impl Root {
     pub fn with_root_node<E: IntoChildElementSpawner>(mut self, element: E) -> Self {
-        self.root_element = Arc::new(element.into_element_spawner());
+        self.root_element = static_root(element);
         self
     }
     pub fn set_root_element<E: IntoChildElementSpawner>(&mut self, element: E) {
-        self.root_element = Arc::new(element.into_element_spawner());
+        self.root_element = static_root(element);
+    }
+    pub fn with_builder<F, E>(mut self, builder: F) -> Self
+    where
+        F: Fn(EntityRef) -> E + Send + Sync + 'static,
+        E: IntoChildElementSpawner,
+    {
+        self.set_builder(builder);
+        self
+    }
+    pub fn set_builder<F, E>(&mut self, builder: F)
+    where
+        F: Fn(EntityRef) -> E + Send + Sync + 'static,
+        E: IntoChildElementSpawner,
+    {
+        self.root_element = built_root(builder);
+    }
+    pub fn with_variant<N: Into<String>, E: IntoChildElementSpawner>(
+        mut self,
+        name: N,
+        element: E,
+    ) -> Self {
+        self.set_variant(name, element);
+        self
+    }
+    pub fn set_variant<N: Into<String>, E: IntoChildElementSpawner>(
+        &mut self,
+        name: N,
+        element: E,
+    ) {
+        self.variants.insert(name.into(), static_root(element));
+    }
+    pub fn set_variant_builder<N: Into<String>, F, E>(&mut self, name: N, builder: F)
+    where
+        F: Fn(EntityRef) -> E + Send + Sync + 'static,
+        E: IntoChildElementSpawner,
+    {
+        self.variants.insert(name.into(), built_root(builder));
+    }
+    fn element(&self, variant: Option<&MenuVariant>) -> &RootBuilder {
+        let Some(variant) = variant else {
+            return &self.root_element;
+        };
+        self.variants.get(&variant.0).unwrap_or_else(|| {
+            warn!("unknown menu variant: {}", variant.0);
+            &self.root_element
+        })
     }
 }
 
@@ -108,12 +220,10 @@ impl Default for Root {
     #[inline]
     fn default() -> Self {
         Self {
-            root_element: Arc::new(
-                Centered {
-                    content: sized::Sized::expanded(Column::new(())),
-                }
-                .into_element_spawner(),
-            ),
+            root_element: static_root(Centered {
+                content: sized::Sized::expanded(Column::new(())),
+            }),
+            variants: HashMap::new(),
         }
     }
 }
@@ -122,6 +232,64 @@ impl<M: Component> MenuPlugin<M> {
     pub fn set_root_element<E: IntoChildElementSpawner>(&mut self, element: E) {
         self.root.set_root_element(element);
     }
+
+    pub fn with_builder<F, E>(mut self, builder: F) -> Self
+    where
+        F: Fn(EntityRef) -> E + Send + Sync + 'static,
+        E: IntoChildElementSpawner,
+    {
+        self.root.set_builder(builder);
+        self
+    }
+
+    pub fn with_variant_builder<N: Into<String>, F, E>(mut self, name: N, builder: F) -> Self
+    where
+        F: Fn(EntityRef) -> E + Send + Sync + 'static,
+        E: IntoChildElementSpawner,
+    {
+        self.root.set_variant_builder(name, builder);
+        self
+    }
+
+    pub fn with_variant<N: Into<String>, E: IntoChildElementSpawner>(
+        mut self,
+        name: N,
+        element: E,
+    ) -> Self {
+        self.root.set_variant(name, element);
+        self
+    }
+
+    pub fn set_variant<N: Into<String>, E: IntoChildElementSpawner>(
+        &mut self,
+        name: N,
+        element: E,
+    ) {
+        self.root.set_variant(name, element);
+    }
+
+    pub fn with_camera<C: Component>(mut self) -> Self {
+        self.camera_binding = Some(Arc::new(|app: &mut App| {
+            app.add_systems(
+                Update,
+                select_menu_camera::<M, C>
+                    .before(show_menu_function::<M>)
+                    .in_set(UiSystems::Add),
+            );
+        }));
+        self
+    }
+
+    pub fn with_state<S: States>(mut self, state: S) -> Self
+    where
+        M: Default,
+    {
+        self.state_binding = Some(Arc::new(move |app: &mut App| {
+            app.add_systems(OnEnter(state.clone()), spawn_menu_root::<M>);
+            app.add_systems(OnExit(state.clone()), destroy_menus::<M>);
+        }));
+        self
+    }
 }
 
 pub trait IntoChildElementSpawner {
@@ -153,6 +321,28 @@ pub trait Element: Send + Sync {
     fn create_bundle(&self, context: &UiContext) -> Self::Bundle;
     fn register_observers(&self, entity_command: &mut EntityCommands, context: &UiContext);
     fn spawn_children(&self, rcs: &mut RelatedSpawnerCommands<ChildOf>, context: Arc<UiContext>);
+
+    #[inline]
+    fn spawn_as_child(&self, rcs: &mut RelatedSpawnerCommands<ChildOf>, context: Arc<UiContext>) {
+        spawn_element(self, rcs, context);
+    }
+}
+
+pub fn spawn_element<E: Element + ?core::marker::Sized>(
+    element: &E,
+    commands: &mut RelatedSpawnerCommands<ChildOf>,
+    context: Arc<UiContext>,
+) {
+    let mut node = Node::default();
+    element.modify_node(&mut node, &context);
+    let themed = Themed::from(context.clone());
+    let mut ec = commands.spawn((node, element.create_bundle(&context)));
+    ec.insert_if_new(themed);
+    element.register_observers(&mut ec, &context);
+    ec.with_children(|rcs| {
+        element.spawn_children(rcs, context);
+    });
+    ec.trigger(|e| Init { entity: e });
 }
 
 #[derive(Resource, Clone)]
@@ -169,6 +359,12 @@ pub struct UiContext {
     pub border_radius: BorderRadius,
     pub highlight_color: Color,
     pub image_color: Color,
+    pub text_justify: Justify,
+    pub text_linebreak: LineBreak,
+    pub text_line_height: LineHeight,
+    pub typography: Typography,
+    pub panel_style: Option<PanelStyle>,
+    pub menu_entity: Option<Entity>,
     pub current_animator: Option<Entity>,
 }
 
@@ -199,6 +395,12 @@ impl FromWorld for UiContext {
             border_radius: BorderRadius::MAX,
             highlight_color: Color::linear_rgb(1.0, 0.0, 0.0),
             image_color: Color::WHITE,
+            text_justify: Justify::Center,
+            text_linebreak: LineBreak::WordBoundary,
+            text_line_height: LineHeight::RelativeToFont(1.2),
+            typography: Typography::new(),
+            panel_style: None,
+            menu_entity: None,
             current_animator: None,
         }
     }
@@ -215,6 +417,7 @@ impl UiContext {
 pub trait ChildElementSpawner: Send + Sync {
     fn spawn(&self, commands: &mut RelatedSpawnerCommands<ChildOf>, context: Arc<UiContext>);
     fn insert_root(&self, commands: &mut EntityCommands, context: Arc<UiContext>);
+    fn remove_root(&self, commands: &mut EntityCommands);
 }
 pub struct ElementSpawnerImpl<E: Element> {
     e: E,
@@ -222,16 +425,7 @@ pub struct ElementSpawnerImpl<E: Element> {
 
 impl<E: Element> ChildElementSpawner for ElementSpawnerImpl<E> {
     fn spawn(&self, commands: &mut RelatedSpawnerCommands<ChildOf>, context: Arc<UiContext>) {
-        let mut node = Node::default();
-        self.e.modify_node(&mut node, &context);
-        let themed = Themed::from(context.clone());
-        let mut ec = commands.spawn((node, self.e.create_bundle(&context)));
-        ec.insert_if_new(themed);
-        self.e.register_observers(&mut ec, &context);
-        ec.with_children(|rcs| {
-            self.e.spawn_children(rcs, context);
-        });
-        ec.trigger(|e| Init { entity: e });
+        self.e.spawn_as_child(commands, context);
     }
 
     fn insert_root(&self, commands: &mut EntityCommands, context: Arc<UiContext>) {
@@ -246,6 +440,36 @@ impl<E: Element> ChildElementSpawner for ElementSpawnerImpl<E> {
         });
         ec.trigger(|e| Init { entity: e });
     }
+
+    fn remove_root(&self, commands: &mut EntityCommands) {
+        commands.remove::<E::Bundle>().remove::<(Node, Themed)>();
+    }
+}
+
+#[derive(Component)]
+pub struct MenuRoot;
+
+#[derive(Component, Clone, PartialEq, Eq, Debug)]
+pub struct MenuVariant(pub String);
+
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MenuCamera(pub Entity);
+
+#[derive(Component)]
+struct StateMenuRoot;
+
+#[derive(Component)]
+#[relationship(relationship_target = MenuRootChildren)]
+pub struct SpawnedByMenu(pub Entity);
+
+#[derive(Component)]
+#[relationship_target(relationship = SpawnedByMenu)]
+pub struct MenuRootChildren(Vec<Entity>);
+
+#[derive(Component)]
+struct ActiveRoot {
+    builder: RootBuilder,
+    spawner: RootSpawner,
 }
 
 #[derive(Resource)]
@@ -254,6 +478,36 @@ pub struct Menu<M: Component> {
     _pd: PhantomData<M>,
 }
 
+impl<M: Component> Menu<M> {
+    pub fn set_root_element<E: IntoChildElementSpawner>(&mut self, element: E) {
+        self.root.set_root_element(element);
+    }
+
+    pub fn set_variant<N: Into<String>, E: IntoChildElementSpawner>(
+        &mut self,
+        name: N,
+        element: E,
+    ) {
+        self.root.set_variant(name, element);
+    }
+
+    pub fn set_builder<F, E>(&mut self, builder: F)
+    where
+        F: Fn(EntityRef) -> E + Send + Sync + 'static,
+        E: IntoChildElementSpawner,
+    {
+        self.root.set_builder(builder);
+    }
+
+    pub fn set_variant_builder<N: Into<String>, F, E>(&mut self, name: N, builder: F)
+    where
+        F: Fn(EntityRef) -> E + Send + Sync + 'static,
+        E: IntoChildElementSpawner,
+    {
+        self.root.set_variant_builder(name, builder);
+    }
+}
+
 #[derive(Message)]
 pub struct DestroyMenu<M: Component> {
     pub target: Entity,
@@ -280,7 +534,8 @@ impl<M: Component + Default> Plugin for MenuPlugin<M> {
         app.add_message::<DestroyMenu<M>>();
         app.add_systems(
             Update,
-            show_menu_function::<M>
+            (show_menu_function::<M>, rebuild_menu_function::<M>)
+                .chain()
                 .run_if(resource_exists::<UiContext>)
                 .in_set(UiSystems::Add),
         );
@@ -289,6 +544,12 @@ impl<M: Component + Default> Plugin for MenuPlugin<M> {
         app.add_systems(Update, cleanup::<M>.in_set(UiSystems::Remove));
 
         app.add_systems(Update, clear_just_added.in_set(UiSystems::Finish));
+        if let Some(state_binding) = &self.state_binding {
+            state_binding(app);
+        }
+        if let Some(camera_binding) = &self.camera_binding {
+            camera_binding(app);
+        }
         if !app.is_plugin_added::<SharedMenuStatePlugin>() {
             app.add_plugins(SharedMenuStatePlugin);
         }
@@ -314,7 +575,26 @@ struct SharedMenuStatePlugin;
 
 impl Plugin for SharedMenuStatePlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(PlaceHolders(HashMap::new()));
+        app.init_resource::<PlaceHolders>();
+        app.init_resource::<LayerRoots>();
+        app.init_resource::<UiLayers>();
+        app.init_resource::<UiScaling>();
+        app.add_systems(
+            Update,
+            (apply_ui_scale, track_base_font_size, apply_text_scale).in_set(UiSystems::Finish),
+        );
+        app.add_observer(remove_layer_root);
+        app.add_systems(
+            Update,
+            (move_to_layer, sync_layer_z_index)
+                .chain()
+                .in_set(UiSystems::Finish),
+        );
+        app.add_observer(remove_placeholder);
+        app.add_observer(detach_placeholder_content);
+        app.add_observer(remove_menu_camera);
+        app.add_observer(remove_panel_image);
+        app.add_systems(Update, apply_menu_camera.in_set(UiSystems::Finish));
         app.add_systems(Update, update_slider_style);
         app.add_systems(
             Update,
@@ -331,6 +611,45 @@ impl Plugin for SharedMenuStatePlugin {
                 .chain()
                 .in_set(UiSystems::Add),
         );
+        app.add_systems(Update, update_switches.in_set(UiSystems::Add));
+        app.add_systems(Update, update_responsive_nodes.in_set(UiSystems::Add));
+        app.add_systems(Update, animate_atlas.run_if(resource_exists::<Time>));
+        app.add_systems(
+            Update,
+            (sync_resource_injections, sync_material_bindings).in_set(UiSystems::Finish),
+        );
+        app.add_systems(
+            Update,
+            load_image_paths.run_if(resource_exists::<AssetServer>),
+        );
+        app.add_systems(
+            Update,
+            (
+                sync_bound_values::<f32>,
+                sync_bound_values::<bool>,
+                sync_bound_values::<String>,
+                sync_bound_values::<usize>,
+                update_bound_text,
+            )
+                .in_set(UiSystems::Add),
+        );
+        app.add_systems(
+            Update,
+            (
+                (update_text_input, update_text_input_text).chain(),
+                update_dropdown,
+            ),
+        );
+        app.add_systems(
+            PostUpdate,
+            truncate_text_lines.after(BevyUiSystems::PostLayout),
+        );
+        app.add_systems(
+            PostUpdate,
+            update_world_anchors
+                .after(TransformSystems::Propagate)
+                .before(BevyUiSystems::Layout),
+        );
         app.add_systems(
             Update,
             init_resource::<UiContext>.run_if(resource_added::<UiFont>),
@@ -347,52 +666,157 @@ fn init_resource<R: Resource + FromWorld>(mut commands: Commands) {
     commands.init_resource::<R>();
 }
 
-#[derive(Resource, Deref, DerefMut)]
-struct PlaceHolders(HashMap<String, Entity>);
-
-fn update_placeholder(
-    mut placeholders: ResMut<PlaceHolders>,
-    query: Query<(Entity, &PlaceholderTarget), Added<PlaceholderTarget>>,
-) {
-    for (e, pt) in query {
-        placeholders.insert(pt.0.clone(), e);
-    }
-}
-
-fn move_to_placeholder_target(
-    inserts: Query<(Entity, Ref<InsertPlaceholderTraget>)>,
-    placeholders: Res<PlaceHolders>,
+fn show_menu_function<M: Component>(
     mut commands: Commands,
+    menu: Res<Menu<M>>,
+    context: Res<UiContext>,
+    query: Query<(Entity, Option<&MenuVariant>), Added<M>>,
+    entities: Query<EntityRef>,
+    just_removed: Res<JustRemovedEntities>,
 ) {
-    for (e, i) in inserts {
-        if i.is_added() || placeholders.is_changed() {
-            let Some(p) = placeholders.get(&i.0).cloned() else {
-                warn!("placeholder target not found");
-                continue;
-            };
-            info!("placing as child of placeholder");
-            commands.entity(e).insert(ChildOf(p));
+    for (e, variant) in query {
+        if just_removed.0.contains(&e) {
+            info!("menu already destroyed");
+            continue;
         }
+        let Ok(entity) = entities.get(e) else {
+            continue;
+        };
+        let builder = menu.root.element(variant).clone();
+        let spawner = builder(entity);
+
+        let existing = menu_owned_entities(&entity);
+        let mut ec = commands.entity(e);
+        ec.insert(MenuRoot);
+        spawner.insert_root(&mut ec, menu_context(&context, e));
+        ec.insert(ActiveRoot { builder, spawner });
+        commands.queue(move |world: &mut World| track_menu_spawned(world, e, existing));
     }
 }
 
-fn show_menu_function<M: Component>(
+#[allow(clippy::type_complexity)]
+fn rebuild_menu_function<M: Component>(
     mut commands: Commands,
     menu: Res<Menu<M>>,
     context: Res<UiContext>,
-    query: Query<Entity, Added<M>>,
+    query: Query<(Entity, Option<Ref<MenuVariant>>, &ActiveRoot), With<M>>,
+    entities: Query<EntityRef>,
+    mut removed_variants: RemovedComponents<MenuVariant>,
     just_removed: Res<JustRemovedEntities>,
 ) {
-    for e in query {
+    let removed: EntityHashSet = removed_variants.read().collect();
+    for (e, variant, active) in query {
         if just_removed.0.contains(&e) {
-            info!("menu already destroyed");
             continue;
         }
-        let arc = Arc::new(context.clone());
+        if !menu.is_changed()
+            && !removed.contains(&e)
+            && !variant.as_ref().is_some_and(|v| v.is_changed())
+        {
+            continue;
+        }
+        let builder = menu.root.element(variant.as_deref());
+        if Arc::ptr_eq(builder, &active.builder) {
+            continue;
+        }
+        let Ok(entity) = entities.get(e) else {
+            continue;
+        };
+        info!("rebuilding menu: {:?}", e);
+        let spawner = builder(entity);
+        let spawned: Vec<Entity> = entity
+            .get::<MenuRootChildren>()
+            .map(|c| c.iter().collect())
+            .unwrap_or_default();
+        let existing: Vec<Entity> = menu_owned_entities(&entity)
+            .into_iter()
+            .filter(|c| !spawned.contains(c))
+            .collect();
+        for s in spawned {
+            commands.entity(s).try_despawn();
+        }
+        let mut ec = commands.entity(e);
+        active.spawner.remove_root(&mut ec);
+        spawner.insert_root(&mut ec, menu_context(&context, e));
+        ec.insert(ActiveRoot {
+            builder: builder.clone(),
+            spawner,
+        });
+        commands.queue(move |world: &mut World| track_menu_spawned(world, e, existing));
+    }
+}
+
+fn menu_owned_entities(entity: &EntityRef) -> Vec<Entity> {
+    let children = entity.get::<Children>().into_iter().flat_map(|c| c.iter());
+    let observers = entity
+        .get::<ObservedBy>()
+        .into_iter()
+        .flat_map(|o| o.get().iter().copied());
+    children.chain(observers).collect()
+}
+
+fn track_menu_spawned(world: &mut World, menu: Entity, existing: Vec<Entity>) {
+    let Ok(entity) = world.get_entity(menu) else {
+        return;
+    };
+    let spawned: Vec<Entity> = menu_owned_entities(&entity)
+        .into_iter()
+        .filter(|e| !existing.contains(e))
+        .collect();
+    for e in spawned {
+        if let Ok(mut ec) = world.get_entity_mut(e) {
+            ec.insert(SpawnedByMenu(menu));
+        }
+    }
+}
+
+fn menu_context(context: &UiContext, menu_entity: Entity) -> Arc<UiContext> {
+    Arc::new(UiContext {
+        menu_entity: Some(menu_entity),
+        ..context.clone()
+    })
+}
+
+#[allow(clippy::type_complexity)]
+fn select_menu_camera<M: Component, C: Component>(
+    mut commands: Commands,
+    menus: Query<Entity, (With<M>, Without<MenuCamera>)>,
+    cameras: Query<Entity, (With<Camera>, With<C>)>,
+) {
+    let Some(camera) = cameras.iter().next() else {
+        return;
+    };
+    for e in menus {
+        commands.entity(e).insert(MenuCamera(camera));
+    }
+}
 
-        menu.root
-            .root_element
-            .insert_root(&mut commands.entity(e), arc);
+fn apply_menu_camera(
+    mut commands: Commands,
+    query: Query<(Entity, &MenuCamera), Changed<MenuCamera>>,
+) {
+    for (e, camera) in query {
+        commands.entity(e).insert(UiTargetCamera(camera.0));
+    }
+}
+
+fn remove_menu_camera(on: On<Remove, MenuCamera>, mut commands: Commands) {
+    commands.entity(on.entity).try_remove::<UiTargetCamera>();
+}
+
+fn spawn_menu_root<M: Component + Default>(mut commands: Commands, query: Query<(), With<M>>) {
+    if query.is_empty() {
+        info!("spawning menu root");
+        commands.spawn((M::default(), StateMenuRoot));
+    }
+}
+
+fn destroy_menus<M: Component>(
+    query: Query<Entity, (With<M>, With<StateMenuRoot>)>,
+    mut messages: MessageWriter<DestroyMenu<M>>,
+) {
+    for e in query {
+        messages.write(DestroyMenu::new(e));
     }
 }
 
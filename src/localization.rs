@@ -0,0 +1,249 @@
+use std::{io, sync::Arc};
+
+use bevy::{
+    app::{App, Plugin, Update},
+    asset::{Asset, AssetApp, AssetEvent, AssetLoader, Assets, Handle, LoadContext, io::Reader},
+    ecs::{
+        change_detection::DetectChanges,
+        component::Component,
+        hierarchy::ChildOf,
+        message::MessageReader,
+        relationship::RelatedSpawnerCommands,
+        resource::Resource,
+        schedule::IntoScheduleConfigs,
+        system::{EntityCommands, Query, Res},
+        world::Ref,
+    },
+    platform::collections::HashMap,
+    reflect::TypePath,
+    text::{Font, TextFont},
+    ui::{Node, widget::Text as UiText},
+};
+
+use crate::{Element, Text, TextSizing, UiContext, UiSystems, typography::ThemedText};
+
+#[derive(Asset, TypePath, Default)]
+pub struct LocaleMessages(pub HashMap<String, String>);
+
+impl LocaleMessages {
+    pub fn parse(source: &str) -> Self {
+        let messages = source
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+            .filter_map(|l| l.split_once('='))
+            .map(|(k, v)| (k.trim().to_string(), v.trim().replace("\\n", "\n")))
+            .collect();
+        Self(messages)
+    }
+}
+
+#[derive(TypePath, Default)]
+pub struct LocaleMessagesLoader;
+
+impl AssetLoader for LocaleMessagesLoader {
+    type Asset = LocaleMessages;
+    type Settings = ();
+    type Error = io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let source =
+            String::from_utf8(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok(LocaleMessages::parse(&source))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["lang"]
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct Localization {
+    locale: String,
+    messages: HashMap<String, Handle<LocaleMessages>>,
+    fonts: HashMap<String, Handle<Font>>,
+}
+
+impl Localization {
+    #[inline]
+    pub fn new<T: Into<String>>(locale: T) -> Self {
+        Self {
+            locale: locale.into(),
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn with_locale<T: Into<String>>(
+        mut self,
+        locale: T,
+        messages: Handle<LocaleMessages>,
+    ) -> Self {
+        self.messages.insert(locale.into(), messages);
+        self
+    }
+
+    #[inline]
+    pub fn with_font<T: Into<String>>(mut self, locale: T, font: Handle<Font>) -> Self {
+        self.fonts.insert(locale.into(), font);
+        self
+    }
+
+    #[inline]
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    #[inline]
+    pub fn set_locale<T: Into<String>>(&mut self, locale: T) {
+        self.locale = locale.into();
+    }
+
+    #[inline]
+    pub fn font(&self) -> Option<&Handle<Font>> {
+        self.fonts.get(&self.locale)
+    }
+
+    pub fn translate(
+        &self,
+        assets: &Assets<LocaleMessages>,
+        key: &str,
+        args: &[(String, String)],
+    ) -> Option<String> {
+        let messages = assets.get(self.messages.get(&self.locale)?)?;
+        let mut message = messages.0.get(key)?.clone();
+        for (name, value) in args {
+            message = message.replace(&format!("{{{name}}}"), value);
+        }
+        Some(message)
+    }
+}
+
+pub struct LocalizationPlugin;
+
+impl Plugin for LocalizationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<LocaleMessages>();
+        app.init_asset_loader::<LocaleMessagesLoader>();
+        app.init_resource::<Localization>();
+        app.add_systems(
+            Update,
+            (update_localized_text, update_locale_fonts).after(UiSystems::Add),
+        );
+    }
+}
+
+#[derive(Component, Clone)]
+pub struct Localized {
+    pub key: String,
+    pub args: Vec<(String, String)>,
+    pub fallback: Option<String>,
+}
+
+pub struct LocalizedText {
+    pub localized: Localized,
+    pub sizing: TextSizing,
+}
+
+impl LocalizedText {
+    #[inline]
+    pub fn new<T: Into<String>>(key: T, sizing: TextSizing) -> Self {
+        Self {
+            localized: Localized {
+                key: key.into(),
+                args: vec![],
+                fallback: None,
+            },
+            sizing,
+        }
+    }
+
+    #[inline]
+    pub fn with_arg<N: Into<String>, V: ToString>(mut self, name: N, value: V) -> Self {
+        self.localized.args.push((name.into(), value.to_string()));
+        self
+    }
+
+    #[inline]
+    pub fn with_fallback<T: Into<String>>(mut self, fallback: T) -> Self {
+        self.localized.fallback = Some(fallback.into());
+        self
+    }
+}
+
+impl Element for LocalizedText {
+    type Bundle = (Localized, <Text as Element>::Bundle);
+
+    #[inline]
+    fn create_bundle(&self, context: &UiContext) -> Self::Bundle {
+        let text = self
+            .localized
+            .fallback
+            .clone()
+            .unwrap_or_else(|| self.localized.key.clone());
+        (
+            self.localized.clone(),
+            Text::new(text, self.sizing).create_bundle(context),
+        )
+    }
+
+    #[inline]
+    fn register_observers(&self, _entity_command: &mut EntityCommands, _context: &UiContext) {}
+
+    #[inline]
+    fn spawn_children(&self, _rcs: &mut RelatedSpawnerCommands<ChildOf>, _context: Arc<UiContext>) {
+    }
+
+    #[inline]
+    fn modify_node(&self, _node: &mut Node, _context: &UiContext) {}
+}
+
+fn update_localized_text(
+    localization: Res<Localization>,
+    assets: Res<Assets<LocaleMessages>>,
+    mut asset_events: MessageReader<AssetEvent<LocaleMessages>>,
+    query: Query<(Ref<Localized>, &mut UiText)>,
+) {
+    let reload = localization.is_changed() || asset_events.read().count() > 0;
+    for (localized, mut text) in query {
+        if !reload && !localized.is_changed() {
+            continue;
+        }
+        let translated = localization
+            .translate(&assets, &localized.key, &localized.args)
+            .or_else(|| localized.fallback.clone())
+            .unwrap_or_else(|| localized.key.clone());
+        if text.0 != translated {
+            text.0 = translated;
+        }
+    }
+}
+
+fn update_locale_fonts(
+    localization: Res<Localization>,
+    query: Query<(&ThemedText, &mut TextFont)>,
+) {
+    let reload = localization.is_changed();
+    for (themed, mut font) in query {
+        if !reload && !font.is_added() {
+            continue;
+        }
+        let context_font = &themed.0;
+        let uses_ui_font =
+            font.font == *context_font || localization.fonts.values().any(|f| *f == font.font);
+        if !uses_ui_font {
+            continue;
+        }
+        let locale_font = localization.font().unwrap_or(context_font);
+        if font.font != *locale_font {
+            font.font = locale_font.clone();
+        }
+    }
+}
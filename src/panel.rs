@@ -0,0 +1,136 @@
+use std::sync::Arc;
+
+use bevy::{
+    asset::Handle,
+    color::Color,
+    ecs::{
+        component::Component,
+        hierarchy::ChildOf,
+        lifecycle::Remove,
+        observer::On,
+        relationship::RelatedSpawnerCommands,
+        system::{Commands, EntityCommands, Query},
+    },
+    prelude::Image as UiImage,
+    ui::{
+        BackgroundColor, BorderColor, Node,
+        prelude::BorderRect,
+        widget::{ImageNode, NodeImageMode},
+    },
+};
+
+use crate::{
+    Element, UiContext,
+    events::Init,
+    image::{sliced_mode, tiled_mode},
+};
+
+#[derive(Clone)]
+pub struct PanelStyle {
+    pub image: Handle<UiImage>,
+    pub image_mode: NodeImageMode,
+    pub color: Color,
+}
+
+impl PanelStyle {
+    #[inline]
+    pub fn sliced(image: Handle<UiImage>, insets: BorderRect) -> Self {
+        Self {
+            image,
+            image_mode: sliced_mode(insets),
+            color: Color::WHITE,
+        }
+    }
+
+    #[inline]
+    pub fn tiled(image: Handle<UiImage>, stretch_value: f32) -> Self {
+        Self {
+            image,
+            image_mode: tiled_mode(stretch_value),
+            color: Color::WHITE,
+        }
+    }
+
+    #[inline]
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+#[derive(Component, Clone)]
+pub struct PanelImage(Option<ImageNode>);
+
+pub struct Panel<E: Element> {
+    pub content: E,
+}
+
+impl<E: Element> Panel<E> {
+    #[inline]
+    pub fn new(content: E) -> Self {
+        Self { content }
+    }
+}
+
+impl<E: Element> Element for Panel<E> {
+    type Bundle = (PanelImage, BackgroundColor, BorderColor, E::Bundle);
+
+    #[inline]
+    fn modify_node(&self, node: &mut Node, context: &UiContext) {
+        if context.panel_style.is_none() {
+            node.border = context.border_thickness;
+            node.border_radius = context.border_radius;
+        }
+        self.content.modify_node(node, context);
+    }
+
+    #[inline]
+    fn create_bundle(&self, context: &UiContext) -> Self::Bundle {
+        let (background, border) = match &context.panel_style {
+            Some(_) => (BackgroundColor(Color::NONE), BorderColor::all(Color::NONE)),
+            None => (
+                BackgroundColor(context.background_color),
+                context.border_color,
+            ),
+        };
+        let image = context.panel_style.as_ref().map(|style| ImageNode {
+            color: style.color,
+            image: style.image.clone(),
+            image_mode: style.image_mode.clone(),
+            ..Default::default()
+        });
+        (
+            PanelImage(image),
+            background,
+            border,
+            self.content.create_bundle(context),
+        )
+    }
+
+    #[inline]
+    fn register_observers(&self, entity_command: &mut EntityCommands, context: &UiContext) {
+        self.content.register_observers(entity_command, context);
+        entity_command.observe(init_panel_image);
+    }
+
+    #[inline]
+    fn spawn_children(&self, rcs: &mut RelatedSpawnerCommands<ChildOf>, context: Arc<UiContext>) {
+        self.content.spawn_children(rcs, context);
+    }
+}
+
+fn init_panel_image(on: On<Init>, query: Query<&PanelImage>, mut commands: Commands) {
+    if let Ok(PanelImage(Some(image))) = query.get(on.entity) {
+        commands.entity(on.entity).insert(image.clone());
+    }
+}
+
+pub(crate) fn remove_panel_image(
+    on: On<Remove, PanelImage>,
+    query: Query<&PanelImage>,
+    mut commands: Commands,
+) {
+    if let Ok(PanelImage(Some(_))) = query.get(on.entity) {
+        commands.entity(on.entity).try_remove::<ImageNode>();
+    }
+}
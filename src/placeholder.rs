@@ -1,12 +1,29 @@
 use bevy::{
-    ecs::{component::Component, entity::Entity, event::EntityEvent},
+    ecs::{
+        change_detection::DetectChanges,
+        component::Component,
+        entity::Entity,
+        event::EntityEvent,
+        hierarchy::ChildOf,
+        lifecycle::Remove,
+        observer::On,
+        query::{Added, Has, With},
+        relationship::RelationshipTarget,
+        resource::Resource,
+        system::{Commands, Query, Res, ResMut},
+        world::Ref,
+    },
+    log::{info, warn},
+    platform::collections::HashMap,
+    prelude::{Deref, DerefMut},
     reflect::Reflect,
 };
 
-use crate::Element;
+use crate::{Element, MenuRoot};
 
 pub struct Placeholder {
     pub name: String,
+    pub policy: PlaceholderPolicy,
 }
 
 #[derive(Component, Reflect)]
@@ -15,19 +32,48 @@ pub struct PlaceholderTarget(pub String);
 #[derive(Component, Reflect)]
 pub struct InsertPlaceholderTraget(pub String);
 
+#[derive(Component, Reflect, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum PlaceholderPolicy {
+    #[default]
+    Append,
+    Replace,
+}
+
+#[derive(Component, Reflect)]
+pub struct PlaceholderScope(pub Entity);
+
+#[derive(Component, Reflect)]
+pub struct PlaceholderFallback;
+
+#[derive(Component)]
+#[relationship(relationship_target = PlaceholderContents)]
+pub struct InPlaceholder(pub Entity);
+
+#[derive(Component)]
+#[relationship_target(relationship = InPlaceholder)]
+pub struct PlaceholderContents(Vec<Entity>);
+
 impl Placeholder {
     pub fn new<T: Into<String>>(name: T) -> Self {
-        Self { name: name.into() }
+        Self {
+            name: name.into(),
+            policy: PlaceholderPolicy::default(),
+        }
+    }
+
+    pub fn with_policy(mut self, policy: PlaceholderPolicy) -> Self {
+        self.policy = policy;
+        self
     }
 }
 
 impl Element for Placeholder {
-    type Bundle = PlaceholderTarget;
+    type Bundle = (PlaceholderTarget, PlaceholderPolicy);
 
     fn modify_node(&self, _node: &mut bevy::ui::Node, _context: &crate::UiContext) {}
 
     fn create_bundle(&self, _context: &crate::UiContext) -> Self::Bundle {
-        PlaceholderTarget(self.name.clone())
+        (PlaceholderTarget(self.name.clone()), self.policy)
     }
 
     fn register_observers(
@@ -54,3 +100,141 @@ pub struct PlaceholderCreated {
     pub entity: Entity,
     pub name: String,
 }
+
+#[derive(EntityEvent)]
+pub struct PlaceholderContentInserted {
+    pub entity: Entity,
+    pub placeholder: Entity,
+    pub name: String,
+}
+
+#[derive(EntityEvent)]
+pub struct PlaceholderContentEvicted {
+    pub entity: Entity,
+    pub placeholder: Entity,
+    pub name: String,
+}
+
+#[derive(Resource, Deref, DerefMut, Default)]
+pub(crate) struct PlaceHolders(HashMap<(Option<Entity>, String), Entity>);
+
+impl PlaceHolders {
+    fn resolve(&self, scope: Option<Entity>, name: &str, fallback: bool) -> Option<Entity> {
+        let key = |scope| (scope, name.to_string());
+        if let Some(p) = self.get(&key(scope)).or_else(|| self.get(&key(None))) {
+            return Some(*p);
+        }
+        if scope.is_some() && !fallback {
+            return None;
+        }
+        let mut matching = self.iter().filter(|((_, n), _)| n == name);
+        let first = matching.next().map(|(_, p)| *p);
+        if matching.next().is_some() {
+            warn!(
+                "placeholder {} exists in several menus, add a PlaceholderScope to pick one",
+                name
+            );
+            return None;
+        }
+        first
+    }
+}
+
+fn menu_scope(
+    entity: Entity,
+    parents: &Query<&ChildOf>,
+    roots: &Query<(), With<MenuRoot>>,
+) -> Option<Entity> {
+    std::iter::once(entity)
+        .chain(parents.iter_ancestors(entity))
+        .find(|e| roots.contains(*e))
+}
+
+pub(crate) fn update_placeholder(
+    mut placeholders: ResMut<PlaceHolders>,
+    query: Query<(Entity, &PlaceholderTarget), Added<PlaceholderTarget>>,
+    parents: Query<&ChildOf>,
+    roots: Query<(), With<MenuRoot>>,
+) {
+    for (e, pt) in query {
+        let scope = menu_scope(e, &parents, &roots);
+        if let Some(existing) = placeholders.insert((scope, pt.0.clone()), e)
+            && existing != e
+        {
+            warn!(
+                "duplicate placeholder {}: {:?} replaces {:?}",
+                pt.0, e, existing
+            );
+        }
+    }
+}
+
+pub(crate) fn remove_placeholder(
+    on: On<Remove, PlaceholderTarget>,
+    mut placeholders: ResMut<PlaceHolders>,
+) {
+    placeholders.retain(|_, p| *p != on.entity);
+}
+
+pub(crate) fn detach_placeholder_content(
+    on: On<Remove, InsertPlaceholderTraget>,
+    query: Query<(), With<InPlaceholder>>,
+    mut commands: Commands,
+) {
+    if query.contains(on.entity) {
+        commands
+            .entity(on.entity)
+            .try_remove::<(InPlaceholder, ChildOf)>();
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn move_to_placeholder_target(
+    inserts: Query<(
+        Entity,
+        Ref<InsertPlaceholderTraget>,
+        Option<&InPlaceholder>,
+        Option<&PlaceholderScope>,
+        Has<PlaceholderFallback>,
+    )>,
+    slots: Query<(&PlaceholderPolicy, Option<&PlaceholderContents>)>,
+    placeholders: Res<PlaceHolders>,
+    parents: Query<&ChildOf>,
+    roots: Query<(), With<MenuRoot>>,
+    mut commands: Commands,
+) {
+    for (e, i, current, scope, fallback) in inserts {
+        if !i.is_added() && !i.is_changed() && !placeholders.is_changed() {
+            continue;
+        }
+        let scope = menu_scope(scope.map(|s| s.0).unwrap_or(e), &parents, &roots);
+        let Some(p) = placeholders.resolve(scope, &i.0, fallback) else {
+            if i.is_added() {
+                warn!("placeholder target not found: {}", i.0);
+            }
+            continue;
+        };
+        if current.map(|c| c.0) == Some(p) {
+            continue;
+        }
+        if let Ok((PlaceholderPolicy::Replace, Some(contents))) = slots.get(p) {
+            for old in contents.iter().filter(|old| *old != e) {
+                commands.trigger(PlaceholderContentEvicted {
+                    entity: old,
+                    placeholder: p,
+                    name: i.0.clone(),
+                });
+                commands
+                    .entity(old)
+                    .try_remove::<(InsertPlaceholderTraget, InPlaceholder, ChildOf)>();
+            }
+        }
+        info!("placing as child of placeholder");
+        commands.entity(e).insert((ChildOf(p), InPlaceholder(p)));
+        commands.trigger(PlaceholderContentInserted {
+            entity: e,
+            placeholder: p,
+            name: i.0.clone(),
+        });
+    }
+}
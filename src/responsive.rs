@@ -0,0 +1,258 @@
+use std::sync::Arc;
+
+use bevy::{
+    ecs::{
+        change_detection::DetectChangesMut,
+        component::Component,
+        hierarchy::ChildOf,
+        query::With,
+        relationship::RelatedSpawnerCommands,
+        system::{EntityCommands, Query},
+        world::World,
+    },
+    math::Vec2,
+    reflect::{PartialReflect, Struct},
+    ui::Node,
+    window::{PrimaryWindow, Window},
+};
+
+use crate::{
+    ChildElementSpawner, Element, IntoChildElementSpawner, UiContext, switch::SwitchState,
+};
+
+type NodeFn = Arc<dyn Fn(&mut Node) + Send + Sync>;
+
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Breakpoint {
+    pub min_width: f32,
+    pub min_height: f32,
+    pub min_aspect: f32,
+}
+
+impl Breakpoint {
+    #[inline]
+    pub fn min_width(width: f32) -> Self {
+        Self {
+            min_width: width,
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn min_height(height: f32) -> Self {
+        Self {
+            min_height: height,
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn min_aspect(aspect: f32) -> Self {
+        Self {
+            min_aspect: aspect,
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn with_min_width(mut self, width: f32) -> Self {
+        self.min_width = width;
+        self
+    }
+
+    #[inline]
+    pub fn with_min_height(mut self, height: f32) -> Self {
+        self.min_height = height;
+        self
+    }
+
+    #[inline]
+    pub fn with_min_aspect(mut self, aspect: f32) -> Self {
+        self.min_aspect = aspect;
+        self
+    }
+
+    #[inline]
+    pub fn matches(&self, size: Vec2) -> bool {
+        let aspect = if size.y > 0.0 { size.x / size.y } else { 0.0 };
+        size.x >= self.min_width && size.y >= self.min_height && aspect >= self.min_aspect
+    }
+}
+
+pub fn window_size(world: &World) -> Option<Vec2> {
+    let mut query = world.try_query_filtered::<&Window, With<PrimaryWindow>>()?;
+    let window = query.iter(world).next()?;
+    Some(Vec2::new(window.width(), window.height()))
+}
+
+pub struct Responsive {
+    cases: Vec<(Breakpoint, Arc<Box<dyn ChildElementSpawner>>)>,
+}
+
+impl Responsive {
+    #[inline]
+    pub fn new() -> Self {
+        Self { cases: vec![] }
+    }
+
+    #[inline]
+    pub fn with_case<E: IntoChildElementSpawner>(
+        mut self,
+        breakpoint: Breakpoint,
+        element: E,
+    ) -> Self {
+        self.add_case(breakpoint, element);
+        self
+    }
+
+    #[inline]
+    pub fn add_case<E: IntoChildElementSpawner>(&mut self, breakpoint: Breakpoint, element: E) {
+        self.cases
+            .push((breakpoint, Arc::new(element.into_element_spawner())));
+    }
+}
+
+impl Default for Responsive {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Element for Responsive {
+    type Bundle = SwitchState;
+
+    #[inline]
+    fn modify_node(&self, _node: &mut Node, _context: &UiContext) {}
+
+    #[inline]
+    fn create_bundle(&self, _context: &UiContext) -> Self::Bundle {
+        let breakpoints: Vec<Breakpoint> = self.cases.iter().map(|(b, _)| *b).collect();
+        SwitchState::new(
+            move |world| {
+                let size = window_size(world)?;
+                breakpoints.iter().rposition(|b| b.matches(size))
+            },
+            self.cases.iter().map(|(_, c)| c.clone()).collect(),
+        )
+    }
+
+    #[inline]
+    fn register_observers(&self, _entity_command: &mut EntityCommands, _context: &UiContext) {}
+
+    #[inline]
+    fn spawn_children(&self, _rcs: &mut RelatedSpawnerCommands<ChildOf>, _context: Arc<UiContext>) {
+    }
+}
+
+#[derive(Component)]
+pub struct ResponsiveNodes {
+    cases: Vec<(Breakpoint, NodeFn)>,
+    restore: Vec<(usize, Box<dyn PartialReflect>)>,
+    active: Option<Vec<usize>>,
+}
+
+pub struct ResponsiveNode<E: Element> {
+    pub content: E,
+    cases: Vec<(Breakpoint, NodeFn)>,
+}
+
+impl<E: Element> ResponsiveNode<E> {
+    #[inline]
+    pub fn new(content: E) -> Self {
+        Self {
+            content,
+            cases: vec![],
+        }
+    }
+
+    #[inline]
+    pub fn with_breakpoint<F>(mut self, breakpoint: Breakpoint, modify: F) -> Self
+    where
+        F: Fn(&mut Node) + Send + Sync + 'static,
+    {
+        self.cases.push((breakpoint, Arc::new(modify)));
+        self
+    }
+
+    #[inline]
+    pub fn responsive<F>(self, breakpoint: Breakpoint, modify: F) -> Self
+    where
+        F: Fn(&mut Node) + Send + Sync + 'static,
+    {
+        self.with_breakpoint(breakpoint, modify)
+    }
+}
+
+impl<E: Element> Element for ResponsiveNode<E> {
+    type Bundle = (ResponsiveNodes, E::Bundle);
+
+    #[inline]
+    fn modify_node(&self, node: &mut Node, context: &UiContext) {
+        self.content.modify_node(node, context);
+    }
+
+    #[inline]
+    fn create_bundle(&self, context: &UiContext) -> Self::Bundle {
+        (
+            ResponsiveNodes {
+                cases: self.cases.clone(),
+                restore: vec![],
+                active: None,
+            },
+            self.content.create_bundle(context),
+        )
+    }
+
+    #[inline]
+    fn register_observers(&self, entity_command: &mut EntityCommands, context: &UiContext) {
+        self.content.register_observers(entity_command, context);
+    }
+
+    #[inline]
+    fn spawn_children(&self, rcs: &mut RelatedSpawnerCommands<ChildOf>, context: Arc<UiContext>) {
+        self.content.spawn_children(rcs, context);
+    }
+}
+
+pub(crate) fn update_responsive_nodes(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    query: Query<(&mut ResponsiveNodes, &mut Node)>,
+) {
+    let Some(window) = windows.iter().next() else {
+        return;
+    };
+    let size = Vec2::new(window.width(), window.height());
+    for (mut responsive, mut node) in query {
+        let active: Vec<usize> = responsive
+            .cases
+            .iter()
+            .enumerate()
+            .filter(|(_, (b, _))| b.matches(size))
+            .map(|(i, _)| i)
+            .collect();
+        if responsive.active.as_ref() == Some(&active) {
+            continue;
+        }
+        let mut base = node.clone();
+        for (i, value) in responsive.restore.drain(..) {
+            if let Some(field) = base.field_at_mut(i) {
+                field.apply(&*value);
+            }
+        }
+        let mut modified = base.clone();
+        for i in &active {
+            (responsive.cases[*i].1)(&mut modified);
+        }
+        responsive.restore = (0..base.field_len())
+            .filter_map(|i| {
+                let original = base.field_at(i)?;
+                let changed = modified.field_at(i)?;
+                (changed.reflect_partial_eq(original) != Some(true))
+                    .then(|| (i, original.to_dynamic()))
+            })
+            .collect();
+        node.set_if_neq(modified);
+        responsive.active = Some(active);
+    }
+}
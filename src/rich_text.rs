@@ -0,0 +1,252 @@
+use std::sync::Arc;
+
+use bevy::{
+    color::{Color, Srgba},
+    ecs::{hierarchy::ChildOf, relationship::RelatedSpawnerCommands, system::EntityCommands},
+    text::{FontWeight, TextColor, TextSpan},
+    ui::Node,
+};
+
+use crate::{
+    Element, Text, TextSizing, UiContext,
+    typography::{TextRole, ThemedText},
+};
+
+#[derive(Clone, Default, PartialEq)]
+pub struct SpanStyle {
+    pub color: Option<String>,
+    pub size: Option<TextSizing>,
+    pub bold: bool,
+}
+
+#[derive(Clone, PartialEq)]
+pub struct RichSpan {
+    pub text: String,
+    pub style: SpanStyle,
+}
+
+#[derive(Clone)]
+pub struct RichText {
+    pub spans: Vec<RichSpan>,
+    pub sizing: TextSizing,
+}
+
+impl RichText {
+    #[inline]
+    pub fn new<T: AsRef<str>>(markup: T, sizing: TextSizing) -> Self {
+        Self {
+            spans: parse_markup(markup.as_ref()),
+            sizing,
+        }
+    }
+}
+
+pub fn parse_markup(markup: &str) -> Vec<RichSpan> {
+    let mut spans: Vec<RichSpan> = vec![];
+    let mut stack: Vec<(String, SpanStyle)> = vec![];
+    let mut text = String::new();
+    let mut chars = markup.chars().peekable();
+    let flush = |text: &mut String, stack: &[(String, SpanStyle)], spans: &mut Vec<RichSpan>| {
+        if text.is_empty() {
+            return;
+        }
+        let style = stack.last().map(|(_, s)| s.clone()).unwrap_or_default();
+        match spans.last_mut() {
+            Some(last) if last.style == style => last.text.push_str(text),
+            _ => spans.push(RichSpan {
+                text: text.clone(),
+                style,
+            }),
+        }
+        text.clear();
+    };
+    while let Some(c) = chars.next() {
+        if c != '[' {
+            text.push(c);
+            continue;
+        }
+        if chars.peek() == Some(&'[') {
+            chars.next();
+            text.push('[');
+            continue;
+        }
+        let mut tag = String::new();
+        let mut closed = false;
+        for c in chars.by_ref() {
+            if c == ']' {
+                closed = true;
+                break;
+            }
+            tag.push(c);
+        }
+        if !closed {
+            text.push('[');
+            text.push_str(&tag);
+            continue;
+        }
+        if let Some(name) = tag.strip_prefix('/') {
+            if let Some(index) = stack.iter().rposition(|(n, _)| n == name.trim()) {
+                flush(&mut text, &stack, &mut spans);
+                stack.truncate(index);
+                continue;
+            }
+        } else {
+            let mut style = stack.last().map(|(_, s)| s.clone()).unwrap_or_default();
+            let (name, value) = match tag.split_once('=') {
+                Some((name, value)) => (name.trim(), Some(value.trim())),
+                None => (tag.trim(), None),
+            };
+            let known = match (name, value) {
+                ("b", None) => {
+                    style.bold = true;
+                    true
+                }
+                ("color", Some(color)) => {
+                    style.color = Some(color.to_string());
+                    true
+                }
+                ("size", Some(size)) => match parse_sizing(size) {
+                    Some(size) => {
+                        style.size = Some(size);
+                        true
+                    }
+                    None => false,
+                },
+                _ => false,
+            };
+            if known {
+                flush(&mut text, &stack, &mut spans);
+                stack.push((name.to_string(), style));
+                continue;
+            }
+        }
+        text.push('[');
+        text.push_str(&tag);
+        text.push(']');
+    }
+    flush(&mut text, &stack, &mut spans);
+    spans
+}
+
+fn parse_sizing(size: &str) -> Option<TextSizing> {
+    match size.to_ascii_lowercase().as_str() {
+        "huge" => Some(TextSizing::Huge),
+        "big" => Some(TextSizing::Big),
+        "small" => Some(TextSizing::Small),
+        "caption" => Some(TextRole::Caption.into()),
+        "body" => Some(TextRole::Body.into()),
+        "subtitle" => Some(TextRole::Subtitle.into()),
+        "title" => Some(TextRole::Title.into()),
+        "display" => Some(TextRole::Display.into()),
+        _ => None,
+    }
+}
+
+pub fn resolve_color(name: &str, context: &UiContext) -> Option<Color> {
+    match name.to_ascii_lowercase().as_str() {
+        "text" => Some(context.text_color),
+        "highlight" => Some(context.highlight_color),
+        "foreground" => Some(context.foreground_color),
+        "background" => Some(context.background_color),
+        "hover" => Some(context.hover_color),
+        "image" => Some(context.image_color),
+        _ => Srgba::hex(name).ok().map(Color::from),
+    }
+}
+
+impl Element for RichText {
+    type Bundle = <Text as Element>::Bundle;
+
+    #[inline]
+    fn create_bundle(&self, context: &UiContext) -> Self::Bundle {
+        Text::new(String::new(), self.sizing).create_bundle(context)
+    }
+
+    #[inline]
+    fn register_observers(&self, _entity_command: &mut EntityCommands, _context: &UiContext) {}
+
+    #[inline]
+    fn spawn_children(&self, rcs: &mut RelatedSpawnerCommands<ChildOf>, context: Arc<UiContext>) {
+        for span in &self.spans {
+            let style = span.style.size.unwrap_or(self.sizing).style(&context);
+            let mut font = style.text_font(&context);
+            if span.style.bold {
+                font.weight = FontWeight::BOLD;
+            }
+            let color = span
+                .style
+                .color
+                .as_deref()
+                .and_then(|c| resolve_color(c, &context))
+                .map(TextColor)
+                .unwrap_or(style.text_color(&context));
+            rcs.spawn((
+                TextSpan::new(span.text.clone()),
+                font,
+                color,
+                ThemedText(context.font.clone()),
+            ));
+        }
+    }
+
+    #[inline]
+    fn modify_node(&self, _node: &mut Node, _context: &UiContext) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_markup;
+    use crate::TextSizing;
+
+    fn spans(markup: &str) -> Vec<(String, bool, Option<String>)> {
+        parse_markup(markup)
+            .into_iter()
+            .map(|s| (s.text, s.style.bold, s.style.color))
+            .collect()
+    }
+
+    fn span(text: &str, bold: bool, color: Option<&str>) -> (String, bool, Option<String>) {
+        (text.to_string(), bold, color.map(str::to_string))
+    }
+
+    #[test]
+    fn parses_nested_tags() {
+        assert_eq!(
+            spans("a [b]b [color=red]c[/color][/b] d"),
+            vec![
+                span("a ", false, None),
+                span("b ", true, None),
+                span("c", true, Some("red")),
+                span(" d", false, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_size_tags() {
+        let parsed = parse_markup("[size=big]x[/size]");
+        assert_eq!(parsed.len(), 1);
+        assert!(parsed[0].style.size == Some(TextSizing::Big));
+    }
+
+    #[test]
+    fn keeps_unknown_and_escaped_tags() {
+        assert_eq!(
+            spans("[[b] [i]x[/i]"),
+            vec![span("[b] [i]x[/i]", false, None)]
+        );
+        assert_eq!(
+            spans("[size=nope]x"),
+            vec![span("[size=nope]x", false, None)]
+        );
+        assert_eq!(spans("a [b"), vec![span("a [b", false, None)]);
+    }
+
+    #[test]
+    fn closing_tag_pops_inner_styles() {
+        assert_eq!(
+            spans("[b][color=red]x[/b]y"),
+            vec![span("x", true, Some("red")), span("y", false, None)]
+        );
+    }
+}
@@ -0,0 +1,224 @@
+use std::ops::RangeInclusive;
+
+use bevy::{
+    ecs::resource::Resource,
+    log::warn,
+    reflect::{
+        DynamicEnum, DynamicVariant, NamedField, PartialReflect, ReflectRef, Struct, TypeInfo,
+        Typed, VariantInfo,
+    },
+    ui::{Val, percent, px},
+};
+
+use crate::{
+    Column, ElementExt, IntoChild, Slider, TextSizing,
+    background::Background,
+    binding::{Binding, BindingValue},
+    border::Border,
+    checkbox::Checkbox,
+    child::Child,
+    dropdown::Dropdown,
+    group::Row,
+    localization::LocalizedText,
+    sized::Sized,
+    text_input::TextInput,
+};
+
+pub fn settings_menu<R: Resource + Struct + Typed>() -> Column<()> {
+    let mut column = Column::new(());
+    let TypeInfo::Struct(info) = R::type_info() else {
+        warn!("settings resource is not a struct");
+        return column;
+    };
+    for field in info.iter() {
+        let Some(widget) = setting_widget::<R>(field) else {
+            warn!("unsupported settings field: {}", field.name());
+            continue;
+        };
+        column.add_element(
+            Row::new(())
+                .with_element(Sized::new(
+                    percent(50),
+                    Val::Auto,
+                    LocalizedText::new(format!("settings.{}", field.name()), TextSizing::Small)
+                        .with_fallback(setting_label(field.name())),
+                ))
+                .with_element(widget),
+        );
+    }
+    column
+}
+
+fn setting_widget<R: Resource + Struct>(field: &NamedField) -> Option<Child> {
+    let name = field.name();
+    if is_number(field) {
+        let (min, max) = setting_range(field);
+        return Some(
+            Sized::new(
+                px(160),
+                px(12),
+                Slider::new_bound(number_binding::<R>(name), min, max),
+            )
+            .into_child(),
+        );
+    }
+    if field.ty().is::<bool>() {
+        return Some(Checkbox::new_bound(value_binding::<R, bool>(name)).into_child());
+    }
+    if field.ty().is::<String>() {
+        return Some(
+            Sized::new(
+                px(160),
+                px(24),
+                Border::all(Background::new(TextInput::new("")))
+                    .bind(value_binding::<R, String>(name)),
+            )
+            .into_child(),
+        );
+    }
+    if let Some(TypeInfo::Enum(info)) = field.type_info() {
+        let variants: Vec<&'static str> = info
+            .iter()
+            .filter(|v| matches!(v, VariantInfo::Unit(_)))
+            .map(VariantInfo::name)
+            .collect();
+        return Some(
+            Sized::new(
+                px(160),
+                px(24),
+                Border::all(Background::new(Dropdown::new(variants.clone())))
+                    .bind(choice_binding::<R>(name, variants)),
+            )
+            .into_child(),
+        );
+    }
+    None
+}
+
+fn setting_label(name: &str) -> String {
+    let name = name.replace('_', " ");
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => name,
+    }
+}
+
+macro_rules! numeric_setting {
+    ($($ty:ty),+) => {
+        fn is_number(field: &NamedField) -> bool {
+            $(field.ty().is::<$ty>())||+
+        }
+
+        fn number_value(value: &dyn PartialReflect) -> Option<f32> {
+            $(
+                if let Some(v) = value.try_downcast_ref::<$ty>() {
+                    return Some(*v as f32);
+                }
+            )+
+            None
+        }
+
+        fn set_number_value(value: &mut dyn PartialReflect, number: f32) -> bool {
+            $(
+                if let Some(v) = value.try_downcast_mut::<$ty>() {
+                    *v = number as $ty;
+                    return true;
+                }
+            )+
+            false
+        }
+    };
+}
+
+numeric_setting!(f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+fn is_integer(field: &NamedField) -> bool {
+    !field.ty().is::<f32>() && !field.ty().is::<f64>()
+}
+
+fn setting_range(field: &NamedField) -> (f32, f32) {
+    if let Some(range) = field.get_attribute::<RangeInclusive<f32>>() {
+        return (*range.start(), *range.end());
+    }
+    if let Some(range) = field.get_attribute::<RangeInclusive<i32>>() {
+        return (*range.start() as f32, *range.end() as f32);
+    }
+    if is_integer(field) {
+        (0.0, 100.0)
+    } else {
+        (0.0, 1.0)
+    }
+}
+
+fn number_binding<R: Resource + Struct>(name: &'static str) -> Binding<f32> {
+    Binding::new(
+        move |world| number_value(world.get_resource::<R>()?.field(name)?),
+        move |world, number| {
+            let Some(mut settings) = world.get_resource_mut::<R>() else {
+                return;
+            };
+            let Some(value) = settings.field_mut(name) else {
+                return;
+            };
+            let is_float = value.try_downcast_ref::<f32>().is_some()
+                || value.try_downcast_ref::<f64>().is_some();
+            let number = if is_float { number } else { number.round() };
+            if !set_number_value(value, number) {
+                warn!("settings field is not a number: {}", name);
+            }
+        },
+    )
+}
+
+fn value_binding<R: Resource + Struct, T: BindingValue>(name: &'static str) -> Binding<T> {
+    Binding::new(
+        move |world| {
+            world
+                .get_resource::<R>()?
+                .field(name)?
+                .try_downcast_ref::<T>()
+                .cloned()
+        },
+        move |world, new_value| {
+            if let Some(value) = world
+                .get_resource_mut::<R>()
+                .as_mut()
+                .and_then(|s| s.field_mut(name))
+                .and_then(|v| v.try_downcast_mut::<T>())
+            {
+                *value = new_value;
+            }
+        },
+    )
+}
+
+fn choice_binding<R: Resource + Struct>(
+    name: &'static str,
+    variants: Vec<&'static str>,
+) -> Binding<usize> {
+    let options = variants.clone();
+    Binding::new(
+        move |world| {
+            let value = world.get_resource::<R>()?.field(name)?;
+            let ReflectRef::Enum(e) = value.reflect_ref() else {
+                return None;
+            };
+            options.iter().position(|o| *o == e.variant_name())
+        },
+        move |world, selected| {
+            let Some(variant) = variants.get(selected) else {
+                return;
+            };
+            let Some(mut settings) = world.get_resource_mut::<R>() else {
+                return;
+            };
+            let Some(value) = settings.field_mut(name) else {
+                return;
+            };
+            if let Err(e) = value.try_apply(&DynamicEnum::new(*variant, DynamicVariant::Unit)) {
+                warn!("failed to apply setting {}: {}", name, e);
+            }
+        },
+    )
+}
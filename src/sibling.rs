@@ -1,12 +1,68 @@
-use crate::{Element, child::Child};
+use crate::Element;
 
-impl Element for (Child, Child) {
+macro_rules! impl_sibling_tuple {
+    ($($name:ident $index:tt),+) => {
+        impl<$($name: Element),+> Element for ($($name,)+) {
+            type Bundle = ();
+
+            #[inline]
+            fn modify_node(&self, _node: &mut bevy::ui::Node, _context: &super::UiContext) {}
+
+            #[inline]
+            fn create_bundle(&self, _context: &super::UiContext) -> Self::Bundle {}
+
+            #[inline]
+            fn register_observers(
+                &self,
+                _entity_command: &mut bevy::ecs::system::EntityCommands,
+                _context: &super::UiContext,
+            ) {
+            }
+
+            #[inline]
+            fn spawn_children(
+                &self,
+                rcs: &mut bevy::ecs::relationship::RelatedSpawnerCommands<bevy::ecs::hierarchy::ChildOf>,
+                context: std::sync::Arc<super::UiContext>,
+            ) {
+                $(self.$index.spawn_as_child(rcs, context.clone());)+
+            }
+
+            #[inline]
+            fn spawn_as_child(
+                &self,
+                rcs: &mut bevy::ecs::relationship::RelatedSpawnerCommands<bevy::ecs::hierarchy::ChildOf>,
+                context: std::sync::Arc<super::UiContext>,
+            ) {
+                self.spawn_children(rcs, context);
+            }
+        }
+    };
+}
+
+impl_sibling_tuple!(A 0);
+impl_sibling_tuple!(A 0, B 1);
+impl_sibling_tuple!(A 0, B 1, C 2);
+impl_sibling_tuple!(A 0, B 1, C 2, D 3);
+impl_sibling_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_sibling_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_sibling_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_sibling_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl_sibling_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+impl_sibling_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+impl_sibling_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+impl_sibling_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
+impl<E: Element> Element for Vec<E> {
     type Bundle = ();
 
+    #[inline]
     fn modify_node(&self, _node: &mut bevy::ui::Node, _context: &super::UiContext) {}
 
+    #[inline]
     fn create_bundle(&self, _context: &super::UiContext) -> Self::Bundle {}
 
+    #[inline]
     fn register_observers(
         &self,
         _entity_command: &mut bevy::ecs::system::EntityCommands,
@@ -14,12 +70,61 @@ impl Element for (Child, Child) {
     ) {
     }
 
+    #[inline]
     fn spawn_children(
         &self,
         rcs: &mut bevy::ecs::relationship::RelatedSpawnerCommands<bevy::ecs::hierarchy::ChildOf>,
         context: std::sync::Arc<super::UiContext>,
     ) {
-        self.0.spawn_children(rcs, context.clone());
-        self.1.spawn_children(rcs, context);
+        for e in self {
+            e.spawn_as_child(rcs, context.clone());
+        }
+    }
+
+    #[inline]
+    fn spawn_as_child(
+        &self,
+        rcs: &mut bevy::ecs::relationship::RelatedSpawnerCommands<bevy::ecs::hierarchy::ChildOf>,
+        context: std::sync::Arc<super::UiContext>,
+    ) {
+        self.spawn_children(rcs, context);
+    }
+}
+
+impl<E: Element, const N: usize> Element for [E; N] {
+    type Bundle = ();
+
+    #[inline]
+    fn modify_node(&self, _node: &mut bevy::ui::Node, _context: &super::UiContext) {}
+
+    #[inline]
+    fn create_bundle(&self, _context: &super::UiContext) -> Self::Bundle {}
+
+    #[inline]
+    fn register_observers(
+        &self,
+        _entity_command: &mut bevy::ecs::system::EntityCommands,
+        _context: &super::UiContext,
+    ) {
+    }
+
+    #[inline]
+    fn spawn_children(
+        &self,
+        rcs: &mut bevy::ecs::relationship::RelatedSpawnerCommands<bevy::ecs::hierarchy::ChildOf>,
+        context: std::sync::Arc<super::UiContext>,
+    ) {
+        for e in self {
+            e.spawn_as_child(rcs, context.clone());
+        }
+    }
+
+    #[inline]
+    fn spawn_as_child(
+        &self,
+        rcs: &mut bevy::ecs::relationship::RelatedSpawnerCommands<bevy::ecs::hierarchy::ChildOf>,
+        context: std::sync::Arc<super::UiContext>,
+    ) {
+        self.spawn_children(rcs, context);
     }
 }
@@ -21,8 +21,15 @@ use bevy::{
 };
 
 use crate::{
-    Element, IntoChild, UiContext, absolute::Absolute, background::Background, border::Border,
-    centered::Centered, positioned::Positioned, sized::Sized, theme::Theme,
+    Element, IntoChild, UiContext,
+    absolute::Absolute,
+    background::Background,
+    binding::{Binding, Bound, ignore_value_change},
+    border::Border,
+    centered::Centered,
+    positioned::Positioned,
+    sized::Sized,
+    theme::Theme,
 };
 use wane_observers::{EntityObserverRegistration, IntoEntityObserverRegistration};
 
@@ -52,7 +59,7 @@ impl Slider<()> {
             value,
             content: Centered {
                 content: (
-                    Border::all(Background { content: () }).into_child(),
+                    Border::all(Background { content: () }),
                     Absolute {
                         content: Positioned {
                             left: px(0),
@@ -63,12 +70,19 @@ impl Slider<()> {
                                 content: Thumb::new_default().into_child(),
                             },
                         },
-                    }
-                    .into_child(),
+                    },
                 ),
             },
         }
     }
+
+    #[inline]
+    pub fn new_bound(binding: Binding<f32>, min: f32, max: f32) -> impl Element {
+        Bound::new(
+            binding,
+            Slider::new_default(&ignore_value_change::<f32>, min, max, min),
+        )
+    }
 }
 
 impl<E: Element> Slider<E> {
@@ -0,0 +1,146 @@
+use std::sync::Arc;
+
+use bevy::{
+    ecs::{
+        component::Component,
+        entity::Entity,
+        hierarchy::{ChildOf, Children},
+        relationship::RelatedSpawnerCommands,
+        resource::Resource,
+        system::EntityCommands,
+        world::World,
+    },
+    log::info,
+    ui::Node,
+};
+
+use crate::{ChildElementSpawner, Element, IntoChildElementSpawner, UiContext, theme::Themed};
+
+type Selector<K> = Arc<dyn Fn(&World) -> Option<K> + Send + Sync>;
+
+pub struct Switch<K> {
+    selector: Selector<K>,
+    cases: Vec<(K, Arc<Box<dyn ChildElementSpawner>>)>,
+}
+
+impl<K: PartialEq + Clone + Send + Sync + 'static> Switch<K> {
+    #[inline]
+    pub fn new<F>(selector: F) -> Self
+    where
+        F: Fn(&World) -> Option<K> + Send + Sync + 'static,
+    {
+        Self {
+            selector: Arc::new(selector),
+            cases: vec![],
+        }
+    }
+
+    #[inline]
+    pub fn from_resource<R: Resource, F>(selector: F) -> Self
+    where
+        F: Fn(&R) -> K + Send + Sync + 'static,
+    {
+        Self::new(move |world| world.get_resource::<R>().map(&selector))
+    }
+
+    #[inline]
+    pub fn with_case<E: IntoChildElementSpawner>(mut self, key: K, element: E) -> Self {
+        self.cases
+            .push((key, Arc::new(element.into_element_spawner())));
+        self
+    }
+
+    #[inline]
+    pub fn add_case<E: IntoChildElementSpawner>(&mut self, key: K, element: E) {
+        self.cases
+            .push((key, Arc::new(element.into_element_spawner())));
+    }
+}
+
+impl<K: PartialEq + Clone + Send + Sync + 'static> Element for Switch<K> {
+    type Bundle = SwitchState;
+
+    #[inline]
+    fn modify_node(&self, _node: &mut Node, _context: &UiContext) {}
+
+    #[inline]
+    fn create_bundle(&self, _context: &UiContext) -> Self::Bundle {
+        let selector = self.selector.clone();
+        let keys: Vec<K> = self.cases.iter().map(|(k, _)| k.clone()).collect();
+        SwitchState {
+            select: Arc::new(move |world| {
+                let key = selector(world)?;
+                keys.iter().position(|k| *k == key)
+            }),
+            cases: self.cases.iter().map(|(_, c)| c.clone()).collect(),
+            current: None,
+        }
+    }
+
+    #[inline]
+    fn register_observers(&self, _entity_command: &mut EntityCommands, _context: &UiContext) {}
+
+    #[inline]
+    fn spawn_children(&self, _rcs: &mut RelatedSpawnerCommands<ChildOf>, _context: Arc<UiContext>) {
+    }
+}
+
+#[derive(Component)]
+pub struct SwitchState {
+    select: Selector<usize>,
+    cases: Vec<Arc<Box<dyn ChildElementSpawner>>>,
+    current: Option<usize>,
+}
+
+impl SwitchState {
+    #[inline]
+    pub(crate) fn new<F>(select: F, cases: Vec<Arc<Box<dyn ChildElementSpawner>>>) -> Self
+    where
+        F: Fn(&World) -> Option<usize> + Send + Sync + 'static,
+    {
+        Self {
+            select: Arc::new(select),
+            cases,
+            current: None,
+        }
+    }
+
+    #[inline]
+    pub fn current(&self) -> Option<usize> {
+        self.current
+    }
+}
+
+pub(crate) fn update_switches(world: &mut World) {
+    let mut query = world.query::<(Entity, &SwitchState)>();
+    let changed: Vec<(Entity, Option<usize>)> = query
+        .iter(world)
+        .filter_map(|(e, state)| {
+            let selected = (state.select)(world);
+            (selected != state.current).then_some((e, selected))
+        })
+        .collect();
+    if changed.is_empty() {
+        return;
+    }
+
+    for (e, selected) in changed {
+        let Some(context) = world.get::<Themed>(e).map(|t| t.context.clone()) else {
+            continue;
+        };
+        let Some(mut state) = world.get_mut::<SwitchState>(e) else {
+            continue;
+        };
+        info!("switching {:?} to case {:?}", e, selected);
+        state.current = selected;
+        let spawner = selected.and_then(|i| state.cases.get(i).cloned());
+
+        world.entity_mut(e).despawn_related::<Children>();
+        if let Some(spawner) = spawner {
+            world.commands().entity(e).with_children(|rcs| {
+                spawner.spawn(rcs, context);
+            });
+        }
+    }
+    world.flush();
+}
@@ -1,41 +1,157 @@
 use std::sync::Arc;
 
 use bevy::{
-    ecs::{hierarchy::ChildOf, relationship::RelatedSpawnerCommands, system::EntityCommands},
-    text::{Justify, TextColor, TextFont, TextLayout},
-    ui::{Node, widget::Text as UiText},
+    ecs::{
+        component::Component,
+        hierarchy::ChildOf,
+        relationship::RelatedSpawnerCommands,
+        system::{EntityCommands, Query},
+    },
+    math::Vec2,
+    text::{Justify, LineBreak, LineHeight, TextColor, TextFont, TextLayout, TextLayoutInfo},
+    ui::{ComputedNode, Node, widget::Text as UiText},
 };
 
-use crate::{Element, UiContext};
+use crate::{
+    Element, UiContext,
+    typography::{TextRole, TextStyle, ThemedText},
+};
+
+const ELLIPSIS: char = '…';
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum TextSizing {
     Huge,
     Big,
     Small,
+    Role(TextRole),
+}
+
+impl TextSizing {
+    #[inline]
+    pub fn role(&self) -> TextRole {
+        match self {
+            TextSizing::Huge => TextRole::Display,
+            TextSizing::Big => TextRole::Title,
+            TextSizing::Small => TextRole::Body,
+            TextSizing::Role(role) => *role,
+        }
+    }
+
+    #[inline]
+    pub fn style(&self, context: &UiContext) -> TextStyle {
+        context.text_style(self.role())
+    }
+
+    #[inline]
+    pub fn font_size(&self, context: &UiContext) -> f32 {
+        self.style(context).size
+    }
+}
+
+impl From<TextRole> for TextSizing {
+    #[inline]
+    fn from(role: TextRole) -> Self {
+        TextSizing::Role(role)
+    }
 }
 
 #[derive(Clone)]
 pub struct Text {
     pub text: String,
     pub sizing: TextSizing,
+    pub justify: Option<Justify>,
+    pub linebreak: Option<LineBreak>,
+    pub line_height: Option<LineHeight>,
+    pub max_lines: Option<usize>,
+}
+
+impl Text {
+    #[inline]
+    pub fn new<T: Into<String>, S: Into<TextSizing>>(text: T, sizing: S) -> Self {
+        Self {
+            text: text.into(),
+            sizing: sizing.into(),
+            justify: None,
+            linebreak: None,
+            line_height: None,
+            max_lines: None,
+        }
+    }
+
+    #[inline]
+    pub fn with_justify(mut self, justify: Justify) -> Self {
+        self.justify = Some(justify);
+        self
+    }
+
+    #[inline]
+    pub fn with_linebreak(mut self, linebreak: LineBreak) -> Self {
+        self.linebreak = Some(linebreak);
+        self
+    }
+
+    #[inline]
+    pub fn with_line_height(mut self, line_height: LineHeight) -> Self {
+        self.line_height = Some(line_height);
+        self
+    }
+
+    #[inline]
+    pub fn with_max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = Some(max_lines);
+        self
+    }
+}
+
+#[derive(Component, Default)]
+pub struct TextMaxLines {
+    pub lines: Option<usize>,
+    full: String,
+    shown: String,
+    truncated_at: Option<Vec2>,
+}
+
+impl TextMaxLines {
+    #[inline]
+    pub fn new(lines: Option<usize>) -> Self {
+        Self {
+            lines,
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn full_text(&self) -> &str {
+        &self.full
+    }
 }
 
 impl Element for Text {
-    type Bundle = (UiText, TextFont, TextColor, TextLayout);
+    type Bundle = (
+        UiText,
+        TextFont,
+        TextColor,
+        TextLayout,
+        LineHeight,
+        TextMaxLines,
+        ThemedText,
+    );
 
     #[inline]
     fn create_bundle(&self, context: &UiContext) -> Self::Bundle {
-        let size = match self.sizing {
-            TextSizing::Huge => context.text_size_big * 2.0,
-            TextSizing::Big => context.text_size_big,
-            TextSizing::Small => context.text_size,
-        };
+        let style = self.sizing.style(context);
         (
             UiText::new(&self.text),
-            TextFont::from_font_size(size).with_font(context.font.clone()),
-            TextColor(context.text_color),
-            TextLayout::new_with_justify(Justify::Center),
+            style.text_font(context),
+            style.text_color(context),
+            TextLayout::new(
+                self.justify.unwrap_or(context.text_justify),
+                self.linebreak.unwrap_or(context.text_linebreak),
+            ),
+            self.line_height.unwrap_or(context.text_line_height),
+            TextMaxLines::new(self.max_lines),
+            ThemedText(context.font.clone()),
         )
     }
 
@@ -49,3 +165,79 @@ impl Element for Text {
     #[inline]
     fn modify_node(&self, _node: &mut Node, _context: &UiContext) {}
 }
+
+fn overflow_index(text: &str, info: &TextLayoutInfo, max_lines: usize) -> Option<usize> {
+    let paragraphs: Vec<usize> = std::iter::once(0)
+        .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+        .collect();
+    let mut line = 0;
+    let mut current: Option<(usize, f32)> = None;
+    for glyph in &info.glyphs {
+        let top = glyph.position.y - glyph.size.y / 2.0;
+        let bottom = glyph.position.y + glyph.size.y / 2.0;
+        match current {
+            Some((paragraph, line_bottom))
+                if glyph.line_index != paragraph || (glyph.size.y > 0.0 && top > line_bottom) =>
+            {
+                line += 1;
+                current = Some((glyph.line_index, bottom));
+            }
+            Some((paragraph, line_bottom)) => {
+                current = Some((paragraph, line_bottom.max(bottom)));
+            }
+            None => current = Some((glyph.line_index, bottom)),
+        }
+        if line >= max_lines {
+            return Some(paragraphs.get(glyph.line_index)? + glyph.byte_index);
+        }
+    }
+    None
+}
+
+pub(crate) fn truncate_text_lines(
+    query: Query<(
+        &mut TextMaxLines,
+        &mut UiText,
+        &TextLayoutInfo,
+        &ComputedNode,
+        Option<&ChildOf>,
+    )>,
+    nodes: Query<&ComputedNode>,
+) {
+    for (mut max_lines, mut text, info, node, parent) in query {
+        let Some(lines) = max_lines.lines.filter(|l| *l > 0) else {
+            continue;
+        };
+        if text.0 != max_lines.shown {
+            max_lines.full = text.0.clone();
+            max_lines.shown = text.0.clone();
+            max_lines.truncated_at = None;
+        }
+        let parent_width = parent
+            .and_then(|p| nodes.get(p.parent()).ok())
+            .map(|p| p.size().x)
+            .unwrap_or_default();
+        let widths = Vec2::new(node.size().x, parent_width);
+        if let Some(truncated_at) = max_lines.truncated_at
+            && widths.cmpgt(truncated_at + 0.5).any()
+        {
+            let full = max_lines.full.clone();
+            text.0 = full.clone();
+            max_lines.shown = full;
+            max_lines.truncated_at = None;
+            continue;
+        }
+        let Some(cut) = overflow_index(&text.0, info, lines) else {
+            continue;
+        };
+        let mut shown = text.0.get(..cut).unwrap_or_default().trim_end().to_string();
+        if shown.ends_with(ELLIPSIS) {
+            shown.pop();
+        }
+        shown.pop();
+        shown.push(ELLIPSIS);
+        text.0 = shown.clone();
+        max_lines.shown = shown;
+        max_lines.truncated_at.get_or_insert(widths);
+    }
+}
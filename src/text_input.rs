@@ -0,0 +1,160 @@
+use std::sync::Arc;
+
+use bevy::{
+    ecs::{
+        change_detection::DetectChanges,
+        component::Component,
+        entity::{Entity, EntityHashSet},
+        hierarchy::ChildOf,
+        lifecycle::RemovedComponents,
+        message::MessageReader,
+        observer::On,
+        query::With,
+        relationship::RelatedSpawnerCommands,
+        system::{Commands, EntityCommands, Query},
+        world::Ref,
+    },
+    input::{
+        ButtonState,
+        keyboard::{Key, KeyboardInput},
+    },
+    picking::events::{Click, Pointer},
+    text::{Justify, TextColor, TextFont, TextLayout},
+    ui::{Node, widget::Text as UiText},
+    ui_widgets::ValueChange,
+};
+
+use crate::{
+    Element, UiContext,
+    typography::{TextRole, ThemedText},
+};
+
+pub struct TextInput {
+    pub value: String,
+}
+
+impl TextInput {
+    #[inline]
+    pub fn new<T: Into<String>>(value: T) -> Self {
+        Self {
+            value: value.into(),
+        }
+    }
+}
+
+#[derive(Component, Clone, Default)]
+pub struct TextInputValue(pub String);
+
+#[derive(Component)]
+pub struct TextInputFocused;
+
+impl Element for TextInput {
+    type Bundle = (
+        TextInputValue,
+        UiText,
+        TextFont,
+        TextColor,
+        TextLayout,
+        ThemedText,
+    );
+
+    #[inline]
+    fn create_bundle(&self, context: &UiContext) -> Self::Bundle {
+        let style = context.text_style(TextRole::Body);
+        (
+            TextInputValue(self.value.clone()),
+            UiText::new(&self.value),
+            style.text_font(context),
+            style.text_color(context),
+            TextLayout::new_with_justify(Justify::Left),
+            ThemedText(context.font.clone()),
+        )
+    }
+
+    #[inline]
+    fn register_observers(&self, entity_command: &mut EntityCommands, _context: &UiContext) {
+        entity_command.observe(focus_text_input);
+    }
+
+    #[inline]
+    fn spawn_children(&self, _rcs: &mut RelatedSpawnerCommands<ChildOf>, _context: Arc<UiContext>) {
+    }
+
+    #[inline]
+    fn modify_node(&self, _node: &mut Node, _context: &UiContext) {}
+}
+
+fn focus_text_input(
+    on: On<Pointer<Click>>,
+    focused: Query<Entity, With<TextInputFocused>>,
+    mut commands: Commands,
+) {
+    for e in focused {
+        if e != on.entity {
+            commands.entity(e).remove::<TextInputFocused>();
+        }
+    }
+    commands.entity(on.entity).insert(TextInputFocused);
+}
+
+pub(crate) fn update_text_input(
+    mut keyboard: MessageReader<KeyboardInput>,
+    mut focused: Query<(Entity, &mut TextInputValue), With<TextInputFocused>>,
+    mut commands: Commands,
+) {
+    for input in keyboard.read() {
+        if input.state != ButtonState::Pressed {
+            continue;
+        }
+        for (e, mut value) in focused.iter_mut() {
+            match &input.logical_key {
+                Key::Backspace => {
+                    if value.0.pop().is_none() {
+                        continue;
+                    }
+                }
+                Key::Enter | Key::Escape => {
+                    commands.entity(e).remove::<TextInputFocused>();
+                    continue;
+                }
+                _ => {
+                    let Some(text) = &input.text else {
+                        continue;
+                    };
+                    if text.chars().all(char::is_control) {
+                        continue;
+                    }
+                    value.0.push_str(text);
+                }
+            }
+            commands.trigger(ValueChange {
+                source: e,
+                value: value.0.clone(),
+            });
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_text_input_text(
+    query: Query<(
+        Entity,
+        Ref<TextInputValue>,
+        &mut UiText,
+        Option<Ref<TextInputFocused>>,
+    )>,
+    mut unfocused: RemovedComponents<TextInputFocused>,
+) {
+    let unfocused: EntityHashSet = unfocused.read().collect();
+    for (e, value, mut text, focused) in query {
+        let focus_added = focused.as_ref().is_some_and(|f| f.is_added());
+        if !value.is_changed() && !focus_added && !unfocused.contains(&e) {
+            continue;
+        }
+        text.0 = if focused.is_some() {
+            format!("{}_", value.0)
+        } else {
+            value.0.clone()
+        };
+    }
+}
@@ -4,11 +4,15 @@ use bevy::{
     asset::Handle,
     color::Color,
     ecs::component::Component,
-    text::Font,
+    text::{Font, Justify, LineBreak, LineHeight},
     ui::{BorderColor, BorderRadius, UiRect},
 };
 
-use crate::{Element, UiContext};
+use crate::{
+    Element, UiContext,
+    panel::PanelStyle,
+    typography::{TextRole, TextStyle},
+};
 
 #[derive(Component)]
 pub struct Themed {
@@ -37,6 +41,11 @@ pub struct Theme<E: Element> {
     propagate_to_children: bool,
     image_color: Option<Color>,
     highlight_color: Option<Color>,
+    text_justify: Option<Justify>,
+    text_linebreak: Option<LineBreak>,
+    text_line_height: Option<LineHeight>,
+    text_roles: Vec<(TextRole, TextStyle)>,
+    panel_style: Option<PanelStyle>,
 }
 
 impl<E: Element> Theme<E> {
@@ -57,6 +66,11 @@ impl<E: Element> Theme<E> {
             border_radius: None,
             content,
             propagate_to_children: true,
+            text_justify: None,
+            text_linebreak: None,
+            text_line_height: None,
+            text_roles: vec![],
+            panel_style: None,
         }
     }
 
@@ -98,10 +112,46 @@ impl<E: Element> Theme<E> {
         self
     }
 
+    #[inline]
+    pub fn with_text_justify(mut self, val: Option<Justify>) -> Self {
+        self.text_justify = val;
+        self
+    }
+
+    #[inline]
+    pub fn with_text_linebreak(mut self, val: Option<LineBreak>) -> Self {
+        self.text_linebreak = val;
+        self
+    }
+
+    #[inline]
+    pub fn with_text_line_height(mut self, val: Option<LineHeight>) -> Self {
+        self.text_line_height = val;
+        self
+    }
+
+    #[inline]
+    pub fn with_text_role(mut self, role: TextRole, style: TextStyle) -> Self {
+        self.text_roles.push((role, style));
+        self
+    }
+
+    #[inline]
+    pub fn with_panel_style(mut self, style: Option<PanelStyle>) -> Self {
+        self.panel_style = style;
+        self
+    }
+
     #[inline]
     pub fn create_context(&self, other: &UiContext) -> UiContext {
+        let mut typography = other.typography.clone();
+        typography.set_sizes(self.text_size, self.text_size_big);
+        for (role, style) in &self.text_roles {
+            typography.set_role(*role, style.clone());
+        }
         UiContext {
             current_animator: other.current_animator,
+            menu_entity: other.menu_entity,
             image_color: self.image_color.unwrap_or(other.image_color),
             highlight_color: self.highlight_color.unwrap_or(other.highlight_color),
             font: self.font.clone().unwrap_or(other.font.clone()),
@@ -114,6 +164,14 @@ impl<E: Element> Theme<E> {
             border_thickness: self.border_thickness.unwrap_or(other.border_thickness),
             border_color: self.border_color.unwrap_or(other.border_color),
             border_radius: self.border_radius.unwrap_or(other.border_radius),
+            text_justify: self.text_justify.unwrap_or(other.text_justify),
+            text_linebreak: self.text_linebreak.unwrap_or(other.text_linebreak),
+            text_line_height: self.text_line_height.unwrap_or(other.text_line_height),
+            typography,
+            panel_style: self
+                .panel_style
+                .clone()
+                .or_else(|| other.panel_style.clone()),
         }
     }
 }
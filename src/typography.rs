@@ -0,0 +1,133 @@
+use bevy::{
+    asset::Handle,
+    color::Color,
+    ecs::component::Component,
+    platform::collections::HashMap,
+    text::{Font, FontWeight, TextColor, TextFont},
+};
+
+use crate::UiContext;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum TextRole {
+    Caption,
+    Body,
+    Subtitle,
+    Title,
+    Display,
+    Custom(&'static str),
+}
+
+#[derive(Component, Clone, PartialEq, Debug)]
+pub struct ThemedText(pub Handle<Font>);
+
+#[derive(Clone)]
+pub struct TextStyle {
+    pub size: f32,
+    pub font: Option<Handle<Font>>,
+    pub weight: FontWeight,
+    pub color: Option<Color>,
+}
+
+impl TextStyle {
+    #[inline]
+    pub fn new(size: f32) -> Self {
+        Self {
+            size,
+            font: None,
+            weight: FontWeight::NORMAL,
+            color: None,
+        }
+    }
+
+    #[inline]
+    pub fn with_font(mut self, font: Handle<Font>) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    #[inline]
+    pub fn with_weight(mut self, weight: FontWeight) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    #[inline]
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    #[inline]
+    pub fn text_font(&self, context: &UiContext) -> TextFont {
+        let mut font = TextFont::from_font_size(self.size)
+            .with_font(self.font.clone().unwrap_or(context.font.clone()));
+        font.weight = self.weight;
+        font
+    }
+
+    #[inline]
+    pub fn text_color(&self, context: &UiContext) -> TextColor {
+        TextColor(self.color.unwrap_or(context.text_color))
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct Typography {
+    roles: HashMap<TextRole, TextStyle>,
+}
+
+impl Typography {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn with_role(mut self, role: TextRole, style: TextStyle) -> Self {
+        self.set_role(role, style);
+        self
+    }
+
+    #[inline]
+    pub fn set_role(&mut self, role: TextRole, style: TextStyle) {
+        self.roles.insert(role, style);
+    }
+
+    #[inline]
+    pub fn role(&self, role: TextRole) -> Option<&TextStyle> {
+        self.roles.get(&role)
+    }
+
+    pub(crate) fn set_sizes(&mut self, text_size: Option<f32>, text_size_big: Option<f32>) {
+        for (role, style) in self.roles.iter_mut() {
+            if let Some(size) = role_size(*role, text_size, text_size_big) {
+                style.size = size;
+            }
+        }
+    }
+}
+
+impl UiContext {
+    #[inline]
+    pub fn text_style(&self, role: TextRole) -> TextStyle {
+        if let Some(style) = self.typography.role(role) {
+            return style.clone();
+        }
+        match role_size(role, Some(self.text_size), Some(self.text_size_big)) {
+            Some(size) => TextStyle::new(size),
+            None => self.text_style(TextRole::Body),
+        }
+    }
+}
+
+fn role_size(role: TextRole, text_size: Option<f32>, text_size_big: Option<f32>) -> Option<f32> {
+    match role {
+        TextRole::Caption => text_size.map(|s| s * 0.8),
+        TextRole::Body => text_size,
+        TextRole::Subtitle => text_size_big.map(|s| s * 0.6),
+        TextRole::Title => text_size_big,
+        TextRole::Display => text_size_big.map(|s| s * 2.0),
+        TextRole::Custom(_) => None,
+    }
+}
@@ -0,0 +1,99 @@
+use bevy::{
+    ecs::{
+        change_detection::DetectChanges,
+        component::Component,
+        entity::Entity,
+        query::{Added, With, Without},
+        resource::Resource,
+        system::{Commands, Local, Query, Res, ResMut},
+    },
+    text::TextFont,
+    ui::UiScale,
+};
+
+use crate::typography::ThemedText;
+
+#[derive(Resource, Clone, Copy, PartialEq, Debug)]
+pub struct UiScaling {
+    pub scale: f32,
+    pub text_scale: f32,
+}
+
+impl Default for UiScaling {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            text_scale: 1.0,
+        }
+    }
+}
+
+impl UiScaling {
+    #[inline]
+    pub fn new(scale: f32) -> Self {
+        Self {
+            scale,
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn with_text_scale(mut self, text_scale: f32) -> Self {
+        self.text_scale = text_scale;
+        self
+    }
+}
+
+#[derive(Component, Clone, Copy, PartialEq, Debug)]
+pub struct BaseFontSize(pub f32);
+
+pub(crate) fn apply_ui_scale(
+    mut base: Local<Option<f32>>,
+    scaling: Res<UiScaling>,
+    ui_scale: Option<ResMut<UiScale>>,
+) {
+    let Some(mut ui_scale) = ui_scale else {
+        return;
+    };
+    let base = *base.get_or_insert(ui_scale.0);
+    if !scaling.is_changed() {
+        return;
+    }
+    let scale = base * scaling.scale;
+    if ui_scale.0 != scale {
+        ui_scale.0 = scale;
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn track_base_font_size(
+    mut commands: Commands,
+    scaling: Res<UiScaling>,
+    query: Query<
+        (Entity, &mut TextFont),
+        (Added<TextFont>, With<ThemedText>, Without<BaseFontSize>),
+    >,
+) {
+    for (e, mut font) in query {
+        commands.entity(e).insert(BaseFontSize(font.font_size));
+        let size = font.font_size * scaling.text_scale;
+        if font.font_size != size {
+            font.font_size = size;
+        }
+    }
+}
+
+pub(crate) fn apply_text_scale(
+    scaling: Res<UiScaling>,
+    query: Query<(&BaseFontSize, &mut TextFont)>,
+) {
+    if !scaling.is_changed() {
+        return;
+    }
+    for (base, mut font) in query {
+        let size = base.0 * scaling.text_scale;
+        if font.font_size != size {
+            font.font_size = size;
+        }
+    }
+}
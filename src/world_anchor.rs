@@ -0,0 +1,197 @@
+use std::sync::Arc;
+
+use bevy::{
+    camera::{Camera, visibility::Visibility},
+    ecs::{
+        change_detection::DetectChangesMut,
+        component::Component,
+        entity::Entity,
+        hierarchy::ChildOf,
+        query::Without,
+        relationship::RelatedSpawnerCommands,
+        system::{EntityCommands, Query, Res},
+    },
+    math::{Rect, Vec2, Vec3},
+    transform::components::GlobalTransform,
+    ui::{ComputedNode, Node, PositionType, UiGlobalTransform, UiScale, Val},
+};
+
+use crate::{Element, UiContext};
+
+#[derive(Component, Clone, Debug)]
+pub struct WorldAnchor {
+    pub target: Entity,
+    pub camera: Option<Entity>,
+    pub world_offset: Vec3,
+    pub offset: Vec2,
+    pub pivot: Vec2,
+    pub clamp: bool,
+    pub hide_offscreen: bool,
+}
+
+impl WorldAnchor {
+    #[inline]
+    pub fn new(target: Entity) -> Self {
+        Self {
+            target,
+            camera: None,
+            world_offset: Vec3::ZERO,
+            offset: Vec2::ZERO,
+            pivot: Vec2::splat(0.5),
+            clamp: false,
+            hide_offscreen: true,
+        }
+    }
+}
+
+pub struct WorldAnchored<E: Element> {
+    pub content: E,
+    pub anchor: WorldAnchor,
+}
+
+impl<E: Element> WorldAnchored<E> {
+    #[inline]
+    pub fn new(target: Entity, content: E) -> Self {
+        Self {
+            content,
+            anchor: WorldAnchor::new(target),
+        }
+    }
+
+    #[inline]
+    pub fn with_camera(mut self, camera: Entity) -> Self {
+        self.anchor.camera = Some(camera);
+        self
+    }
+
+    #[inline]
+    pub fn with_world_offset(mut self, offset: Vec3) -> Self {
+        self.anchor.world_offset = offset;
+        self
+    }
+
+    #[inline]
+    pub fn with_offset(mut self, offset: Vec2) -> Self {
+        self.anchor.offset = offset;
+        self
+    }
+
+    #[inline]
+    pub fn with_pivot(mut self, pivot: Vec2) -> Self {
+        self.anchor.pivot = pivot;
+        self
+    }
+
+    #[inline]
+    pub fn clamped(mut self) -> Self {
+        self.anchor.clamp = true;
+        self
+    }
+
+    #[inline]
+    pub fn with_hide_offscreen(mut self, hide: bool) -> Self {
+        self.anchor.hide_offscreen = hide;
+        self
+    }
+}
+
+impl<E: Element> Element for WorldAnchored<E> {
+    type Bundle = (WorldAnchor, E::Bundle);
+
+    #[inline]
+    fn modify_node(&self, node: &mut Node, context: &UiContext) {
+        self.content.modify_node(node, context);
+        node.position_type = PositionType::Absolute;
+    }
+
+    #[inline]
+    fn create_bundle(&self, context: &UiContext) -> Self::Bundle {
+        (self.anchor.clone(), self.content.create_bundle(context))
+    }
+
+    #[inline]
+    fn register_observers(&self, entity_command: &mut EntityCommands, context: &UiContext) {
+        self.content.register_observers(entity_command, context);
+    }
+
+    #[inline]
+    fn spawn_children(&self, rcs: &mut RelatedSpawnerCommands<ChildOf>, context: Arc<UiContext>) {
+        self.content.spawn_children(rcs, context);
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_world_anchors(
+    anchors: Query<(
+        &WorldAnchor,
+        &mut Node,
+        &mut Visibility,
+        Option<&ComputedNode>,
+        Option<&ChildOf>,
+    )>,
+    parents: Query<(&ComputedNode, &UiGlobalTransform)>,
+    cameras: Query<(Entity, &Camera, &GlobalTransform)>,
+    targets: Query<&GlobalTransform, Without<Camera>>,
+    ui_scale: Option<Res<UiScale>>,
+) {
+    let scale = ui_scale.map(|s| s.0).unwrap_or(1.0).max(f32::EPSILON);
+    for (anchor, mut node, mut visibility, computed, parent) in anchors {
+        let camera = match anchor.camera {
+            Some(camera) => cameras.get(camera).ok(),
+            None => cameras
+                .iter()
+                .filter(|(_, camera, _)| camera.is_active)
+                .min_by_key(|(_, camera, _)| camera.order),
+        };
+        let projected = camera.zip(targets.get(anchor.target).ok()).and_then(
+            |((_, camera, camera_transform), target)| {
+                let world = target.translation() + anchor.world_offset;
+                let position = camera.world_to_viewport(camera_transform, world).ok()?;
+                Some((position, camera.logical_viewport_rect()?))
+            },
+        );
+        let Some((position, viewport)) = projected else {
+            visibility.set_if_neq(visibility_for(!anchor.hide_offscreen));
+            continue;
+        };
+        let size = computed
+            .map(|c| c.size() * c.inverse_scale_factor())
+            .unwrap_or_default();
+        let viewport = Rect::from_corners(viewport.min / scale, viewport.max / scale);
+        let mut top_left = position / scale + anchor.offset - size * anchor.pivot;
+        let onscreen = !Rect::from_corners(top_left, top_left + size)
+            .intersect(viewport)
+            .is_empty()
+            || viewport.contains(top_left);
+        if anchor.clamp {
+            top_left = top_left.clamp(viewport.min, (viewport.max - size).max(viewport.min));
+        }
+        visibility.set_if_neq(visibility_for(
+            onscreen || anchor.clamp || !anchor.hide_offscreen,
+        ));
+
+        let origin = parent
+            .and_then(|p| parents.get(p.parent()).ok())
+            .map(|(parent, transform)| {
+                (transform.translation - parent.size() / 2.0 + parent.border.min_inset)
+                    * parent.inverse_scale_factor()
+            })
+            .unwrap_or_default();
+        let top_left = top_left - origin;
+
+        if node.left != Val::Px(top_left.x) {
+            node.left = Val::Px(top_left.x);
+        }
+        if node.top != Val::Px(top_left.y) {
+            node.top = Val::Px(top_left.y);
+        }
+    }
+}
+
+fn visibility_for(visible: bool) -> Visibility {
+    if visible {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    }
+}